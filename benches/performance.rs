@@ -193,6 +193,49 @@ fn bench_error_handling(c: &mut Criterion) {
     });
 }
 
+// Benchmark response decoding: Value tree (previous path) vs the client's envelope check and typed decode
+fn bench_response_decoding(c: &mut Criterion) {
+    use blocks_production_lib::types::RpcResponse;
+
+    let mut group = c.benchmark_group("response_decoding");
+
+    for validator_count in [1000, 5000].iter() {
+        let mut by_identity = serde_json::Map::new();
+        for i in 0..*validator_count {
+            by_identity.insert(format!("validator_{}", i), json!([100, 95]));
+        }
+        let body = json!({
+            "jsonrpc": "2.0",
+            "result": {
+                "context": { "slot": 2000 },
+                "value": {
+                    "byIdentity": by_identity,
+                    "range": { "firstSlot": 1000, "lastSlot": 2000 }
+                }
+            },
+            "id": 1
+        })
+        .to_string()
+        .into_bytes();
+
+        // Both variants go from response bytes to `BlockProductionValue` and nothing more
+        group.bench_with_input(BenchmarkId::new("value_tree", validator_count), &body, |b, body| {
+            b.iter(|| {
+                let value: serde_json::Value = serde_json::from_slice(body).unwrap();
+                assert!(value.get("error").is_none());
+                let response: RpcResponse = serde_json::from_value(value).unwrap();
+                response.result.value
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("typed", validator_count), &body, |b, body| {
+            b.iter(|| BlockProductionClient::decode_block_production_body(body).unwrap())
+        });
+    }
+
+    group.finish();
+}
+
 // Benchmark statistics calculation
 fn bench_statistics_calculation(c: &mut Criterion) {
     use blocks_production_lib::ValidatorSkipRate;
@@ -235,7 +278,7 @@ fn bench_concurrent_requests(c: &mut Criterion) {
 
             Mock::given(method("POST"))
                 .and(path("/"))
-                .respond_with(ResponseTemplate::new(200).set_body_json(&json!({
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                     "jsonrpc": "2.0",
                     "result": "ok",
                     "id": 1
@@ -271,6 +314,7 @@ criterion_group!(
     bench_config_building,
     bench_data_processing,
     bench_payload_sizes,
+    bench_response_decoding,
     bench_error_handling,
     bench_statistics_calculation,
     bench_concurrent_requests
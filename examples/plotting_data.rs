@@ -1,5 +1,4 @@
 use blocks_production_lib::BlockProductionClient;
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -79,7 +79,7 @@ async fn main() -> Result<()> {
     
     for validator in &data.validators {
         let bucket = match validator.skip_rate_percent {
-            rate if rate == 0.0 => "0.0% (Perfect)",
+            0.0 => "0.0% (Perfect)",
             rate if rate <= 1.0 => "0.1-1.0%",
             rate if rate <= 2.0 => "1.1-2.0%",
             rate if rate <= 5.0 => "2.1-5.0%",
@@ -1,5 +1,5 @@
-use blocks_production_lib::{BlockProductionClient, Result, AlertSeverity};
-use clap::Parser;
+use blocks_production_lib::{redact_url, BlockProductionClient, Result, AlertSeverity};
+use clap::{Parser, Subcommand};
 use colored::*;
 use std::process;
 
@@ -14,6 +14,31 @@ struct Cli {
     /// Use quiet output (minimal info)
     #[arg(short, long)]
     quiet: bool,
+
+    /// Skip the on-chain validator name lookup and show bare public keys
+    #[arg(long)]
+    no_names: bool,
+
+    /// Record all RPC traffic to a cassette file (attach it to bug reports)
+    #[arg(long, value_name = "FILE", conflicts_with = "replay")]
+    record: Option<std::path::PathBuf>,
+
+    /// Replay RPC traffic from a cassette file instead of the network
+    #[arg(long, value_name = "FILE")]
+    replay: Option<std::path::PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Diagnose the RPC endpoint (health, version, cluster, lag, servable slots)
+    Doctor {
+        /// Reference endpoint to measure slot lag against
+        #[arg(short, long)]
+        reference: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -27,14 +52,23 @@ async fn main() {
 }
 
 async fn run_cli(cli: Cli) -> Result<()> {
-    let client = BlockProductionClient::builder()
+    let mut builder = BlockProductionClient::builder()
         .rpc_endpoint(&cli.endpoint)
-        .public_rpc_config()
-        .build()?;
+        .public_rpc_config();
+    if let Some(path) = &cli.record {
+        builder = builder.record_cassette(path);
+    } else if let Some(path) = &cli.replay {
+        builder = builder.replay_cassette(path);
+    }
+    let client = builder.build()?;
+
+    if let Some(Command::Doctor { reference }) = &cli.command {
+        return run_doctor(&client, reference.as_deref()).await;
+    }
 
     // Test connection first
     if !cli.quiet {
-        println!("\x1b[36m[INFO]\x1b[0m Connecting to {}...", cli.endpoint);
+        println!("\x1b[36m[INFO]\x1b[0m Connecting to {}...", redact_url(&cli.endpoint));
     }
     
     client.test_connection().await?;
@@ -44,7 +78,16 @@ async fn run_cli(cli: Cli) -> Result<()> {
     }
 
     // Fetch data
-    let data = client.fetch_block_production().await?;
+    let mut data = client.fetch_block_production().await?;
+
+    // Names are a nice-to-have: keep going with bare pubkeys if the lookup fails
+    if !cli.no_names && !cli.quiet {
+        match client.validator_info().await {
+            Ok(validator_info) => data.apply_validator_info(&validator_info),
+            Err(e) => println!("\x1b[33m[WARNING]\x1b[0m Validator names unavailable: {}\n", e),
+        }
+    }
+
     let stats = &data.statistics;
 
     if cli.quiet {
@@ -157,7 +200,7 @@ async fn run_cli(cli: Cli) -> Result<()> {
     } else {
         println!("\n{}", "TOP 10 MOST PROBLEMATIC VALIDATORS".red().bold());
         println!("{:<47} {:<10} {:<8} {:<10} {:<12} {:<10}", 
-                 "Validator Public Key".white().bold(),
+                 "Validator".white().bold(),
                  "Skip Rate".white().bold(), 
                  "Slots".white().bold(),
                  "Missed".white().bold(),
@@ -179,7 +222,7 @@ async fn run_cli(cli: Cli) -> Result<()> {
             
             println!("   {:<2} {:<44} {:<10} {:<8} {:<10} {:<12} {:<10}",
                      format!("{}.", i + 1).white(),
-                     format_fn(&validator.pubkey),
+                     format_fn(&truncate_name(validator.display_name(), 44)),
                      format_fn(&format!("{:.2}%", validator.skip_rate_percent)),
                      validator.leader_slots.to_string().white(),
                      format_fn(&validator.missed_slots.to_string()),
@@ -196,7 +239,7 @@ async fn run_cli(cli: Cli) -> Result<()> {
             println!("\n{}", "HIGH-STAKE VALIDATORS WITH CONCERNING PERFORMANCE (>1000 slots, >5% skip):".yellow());
             for validator in high_stake_bad.iter().take(3) {
                 println!("   {} -> {:.2}% skip rate, {} slots, {} blocks lost",
-                         validator.pubkey.red(),
+                         validator.display_name().red(),
                          validator.skip_rate_percent,
                          validator.leader_slots,
                          validator.missed_slots);
@@ -211,4 +254,72 @@ async fn run_cli(cli: Cli) -> Result<()> {
     println!("   Fetched At: {}", data.fetched_at.format("%Y-%m-%d %H:%M:%S UTC"));
 
     Ok(())
-}
\ No newline at end of file
+}
+
+async fn run_doctor(client: &BlockProductionClient, reference: Option<&str>) -> Result<()> {
+    let diagnostics = client.diagnose_endpoint_with_reference(reference).await?;
+
+    println!("\x1b[1;35mRPC ENDPOINT DIAGNOSTICS\x1b[0m");
+    println!("═══════════════════════════════════");
+    println!("   Endpoint: {}", diagnostics.rpc_endpoint);
+
+    if diagnostics.healthy {
+        println!("   Health: {}", "ok".green());
+    } else {
+        println!("   Health: {}", "unhealthy".red());
+    }
+    println!("   Cluster: {}", diagnostics.cluster);
+    println!("   Version: {}", diagnostics.version.as_deref().unwrap_or("unknown"));
+    if let Some(feature_set) = diagnostics.feature_set {
+        println!("   Feature Set: {}", feature_set);
+    }
+    if let Some(slot) = diagnostics.slot {
+        println!("   Current Slot: {}", slot);
+    }
+    if let Some(lag) = diagnostics.slot_lag {
+        let lag_text = format!("{} slots", lag);
+        let lag_text = if lag > 50 { lag_text.red() } else if lag > 10 { lag_text.yellow() } else { lag_text.green() };
+        println!("   Slot Lag: {} (vs {})", lag_text, diagnostics.reference_endpoint.as_deref().unwrap_or("reference"));
+    }
+
+    println!("\n\x1b[1;34mLEDGER COVERAGE:\x1b[0m");
+    if let Some(slot) = diagnostics.minimum_ledger_slot {
+        println!("   Minimum Ledger Slot: {}", slot);
+    }
+    if let Some(block) = diagnostics.first_available_block {
+        println!("   First Available Block: {}", block);
+    }
+    match diagnostics.servable_range() {
+        Some(range) => println!("   Servable Range: {} to {} ({} slots)", range.first_slot, range.last_slot, range.slot_count()),
+        None => println!("   Servable Range: unknown"),
+    }
+
+    println!("\n\x1b[1;36mRPC CALLS:\x1b[0m");
+    for call in &diagnostics.calls {
+        match &call.error {
+            None => println!("   {:<40} {}", call.method, format!("{} ms", call.latency_ms).green()),
+            Some(error) => println!("   {:<40} {} ({})", call.method, "FAILED".red(), error),
+        }
+    }
+    if let Some(call) = &diagnostics.reference_call {
+        match &call.error {
+            None => println!("   {:<40} {}", call.method, format!("{} ms", call.latency_ms).green()),
+            Some(error) => println!("   {:<40} {} ({})", call.method, "FAILED".red(), error),
+        }
+    }
+    if let Some(average) = diagnostics.average_latency_ms() {
+        println!("   Average Latency: {:.0} ms", average);
+    }
+
+    Ok(())
+}
+
+/// Shorten a validator name to fit a table column
+fn truncate_name(name: &str, max_chars: usize) -> String {
+    if name.chars().count() <= max_chars {
+        name.to_string()
+    } else {
+        let truncated: String = name.chars().take(max_chars.saturating_sub(3)).collect();
+        format!("{}...", truncated)
+    }
+}
@@ -0,0 +1,411 @@
+use crate::error::{BlockProductionError, Result, TimeoutType};
+use crate::secret::redact_url;
+use crate::transport::{RpcTransport, TransportRequest, TransportResponse};
+use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Record or replay RPC traffic through a cassette file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Forward requests to the real transport and append every interaction to the file
+    Record(PathBuf),
+    /// Serve responses from the file without any network access
+    Replay(PathBuf),
+}
+
+/// Response headers kept in cassettes: the ones the client reads
+///
+/// Everything else (cookies, tracing ids, provider-specific tokens) is dropped.
+const RECORDED_HEADERS: &[&str] = &[
+    "content-type",
+    "retry-after",
+    "x-ratelimit-limit",
+    "x-ratelimit-remaining",
+    "x-ratelimit-reset",
+    "ratelimit-limit",
+    "ratelimit-remaining",
+    "ratelimit-reset",
+];
+
+/// One recorded JSON-RPC request and what came back
+///
+/// Request headers and endpoint URLs are not recorded, and only the response
+/// headers in `RECORDED_HEADERS` are kept, so cassettes can be attached to bug
+/// reports without leaking credentials or session cookies.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CassetteInteraction {
+    /// JSON-RPC method
+    pub method: String,
+    /// JSON-RPC params (matched on replay)
+    #[serde(default)]
+    pub params: serde_json::Value,
+    /// When the interaction was recorded
+    pub recorded_at: DateTime<Utc>,
+    /// Response or transport failure
+    pub outcome: RecordedOutcome,
+}
+
+/// Outcome of a recorded request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum RecordedOutcome {
+    /// The endpoint answered
+    Response {
+        status: u16,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+        /// Response body (stored as a string when it was not valid JSON)
+        body: serde_json::Value,
+        #[serde(default)]
+        body_is_text: bool,
+    },
+    /// The request timed out
+    Timeout {
+        message: String,
+        #[serde(default = "default_timeout_type")]
+        timeout_type: TimeoutType,
+    },
+    /// No response was received
+    ConnectionFailed { message: String },
+    /// The HTTP client failed after connecting (e.g. while reading the body)
+    Http { message: String },
+    /// The body exceeded `max_response_bytes`
+    ResponseTooLarge { limit: usize, received: usize },
+    /// The transport rejected the request before sending it
+    Config {
+        message: String,
+        field: Option<String>,
+        suggestion: Option<String>,
+    },
+}
+
+const fn default_timeout_type() -> TimeoutType {
+    TimeoutType::Request
+}
+
+impl RecordedOutcome {
+    fn from_result(result: &Result<TransportResponse>) -> Self {
+        match result {
+            Ok(response) => {
+                let headers = response.headers.iter()
+                    .filter(|(name, _)| RECORDED_HEADERS.contains(&name.as_str()))
+                    .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+                    .collect();
+                let (body, body_is_text) = match serde_json::from_slice(&response.body) {
+                    Ok(body) => (body, false),
+                    Err(_) => (serde_json::Value::String(String::from_utf8_lossy(&response.body).into_owned()), true),
+                };
+                Self::Response { status: response.status, headers, body, body_is_text }
+            }
+            Err(e @ BlockProductionError::Timeout { timeout_type, .. }) => Self::Timeout {
+                message: e.to_string(),
+                timeout_type: timeout_type.clone(),
+            },
+            Err(BlockProductionError::Http { source, .. }) => Self::Http { message: source.to_string() },
+            Err(BlockProductionError::ResponseTooLarge { limit, received, .. }) => Self::ResponseTooLarge {
+                limit: *limit,
+                received: *received,
+            },
+            Err(BlockProductionError::Config { message, field, suggestion }) => Self::Config {
+                message: message.clone(),
+                field: field.clone(),
+                suggestion: suggestion.clone(),
+            },
+            Err(e) => Self::ConnectionFailed { message: e.to_string() },
+        }
+    }
+
+    fn replay(&self, request: &TransportRequest<'_>) -> Result<TransportResponse> {
+        match self {
+            Self::Response { status, headers, body, body_is_text } => {
+                let body = match (body, body_is_text) {
+                    (serde_json::Value::String(text), true) => text.clone().into_bytes(),
+                    (body, _) => body.to_string().into_bytes(),
+                };
+                Ok(headers.iter().fold(
+                    TransportResponse::new(*status, body),
+                    |response, (name, value)| response.with_header(name, value),
+                ))
+            }
+            Self::Timeout { timeout_type, .. } => Err(BlockProductionError::Timeout {
+                duration: request.timeout,
+                operation: format!("RPC {} request (replayed)", request.method()),
+                timeout_type: timeout_type.clone(),
+            }),
+            Self::ConnectionFailed { message } => Err(BlockProductionError::ConnectionFailed {
+                endpoint: redact_url(request.endpoint),
+                source: message.clone().into(),
+                endpoint_reachable: None,
+            }),
+            Self::Http { message } => Err(replayed_http_error(message, request)),
+            Self::ResponseTooLarge { limit, received } => Err(BlockProductionError::ResponseTooLarge {
+                endpoint: redact_url(request.endpoint),
+                method: request.method().to_string(),
+                limit: *limit,
+                received: *received,
+            }),
+            Self::Config { message, field, suggestion } => Err(BlockProductionError::Config {
+                message: message.clone(),
+                field: field.clone(),
+                suggestion: suggestion.clone(),
+            }),
+        }
+    }
+}
+
+/// Rebuild a recorded HTTP client failure
+///
+/// `reqwest::Error` cannot be constructed directly, so a builder error stands in
+/// for the original; it is retried and failed over exactly like one. The
+/// recorded message is kept as context.
+fn replayed_http_error(message: &str, request: &TransportRequest<'_>) -> BlockProductionError {
+    let context = format!("RPC {} request (replayed): {message}", request.method());
+    // A relative URI is rejected when converted to a reqwest request
+    match reqwest::Request::try_from(http::Request::new(Vec::<u8>::new())) {
+        Err(source) => BlockProductionError::Http { source, context: Some(context) },
+        Ok(_) => BlockProductionError::ConnectionFailed {
+            endpoint: redact_url(request.endpoint),
+            source: context.into(),
+            endpoint_reachable: None,
+        },
+    }
+}
+
+/// Transport that forwards to another transport and records every interaction
+///
+/// The cassette is a JSON Lines file with one `CassetteInteraction` per line,
+/// appended as requests complete.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Arc<dyn RpcTransport>,
+    path: PathBuf,
+    cassette: Mutex<std::fs::File>,
+}
+
+impl RecordingTransport {
+    /// Start a new cassette at `path`, replacing an existing file
+    pub fn new(inner: Arc<dyn RpcTransport>, path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette = std::fs::File::create(&path).map_err(|e| BlockProductionError::config_error(
+            &format!("Cannot create cassette {}: {e}", path.display()),
+            Some("cassette"),
+            Some("Check that the cassette directory exists and is writable"),
+        ))?;
+        Ok(Self {
+            inner,
+            path,
+            cassette: Mutex::new(cassette),
+        })
+    }
+
+    /// Path of the cassette being written
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn record(&self, interaction: &CassetteInteraction) {
+        let line = match serde_json::to_string(interaction) {
+            Ok(line) => line,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to serialize cassette interaction");
+                return;
+            }
+        };
+        let mut cassette = self.cassette.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if let Err(e) = writeln!(cassette, "{line}").and_then(|()| cassette.flush()) {
+            tracing::warn!(cassette = %self.path.display(), error = %e, "Failed to write cassette interaction");
+        }
+    }
+}
+
+impl RpcTransport for RecordingTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>> {
+        Box::pin(async move {
+            let result = self.inner.send(request).await;
+            self.record(&CassetteInteraction {
+                method: request.method().to_string(),
+                params: request.body.get("params").cloned().unwrap_or_default(),
+                recorded_at: Utc::now(),
+                outcome: RecordedOutcome::from_result(&result),
+            });
+            result
+        })
+    }
+}
+
+/// Transport serving responses from a cassette, matched on method and params
+///
+/// Interactions with the same method and params are served in recorded order;
+/// the last one keeps being served. Unmatched requests fail with a
+/// configuration error instead of touching the network.
+#[derive(Debug)]
+pub struct ReplayTransport {
+    interactions: Mutex<Vec<(String, serde_json::Value, VecDeque<RecordedOutcome>)>>,
+}
+
+impl ReplayTransport {
+    /// Load a cassette written by `RecordingTransport`
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| BlockProductionError::config_error(
+            &format!("Cannot read cassette {}: {e}", path.display()),
+            Some("cassette"),
+            Some("Record a cassette first with CassetteMode::Record"),
+        ))?;
+
+        let interactions = contents.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<std::result::Result<Vec<CassetteInteraction>, _>>()?;
+        Ok(Self::from_interactions(interactions))
+    }
+
+    /// Replay interactions held in memory
+    #[must_use]
+    pub fn from_interactions(interactions: Vec<CassetteInteraction>) -> Self {
+        let mut grouped: Vec<(String, serde_json::Value, VecDeque<RecordedOutcome>)> = Vec::new();
+        for interaction in interactions {
+            let existing = grouped.iter_mut()
+                .find(|(method, params, _)| *method == interaction.method && *params == interaction.params);
+            match existing {
+                Some((_, _, outcomes)) => outcomes.push_back(interaction.outcome),
+                None => grouped.push((interaction.method, interaction.params, VecDeque::from([interaction.outcome]))),
+            }
+        }
+        Self {
+            interactions: Mutex::new(grouped),
+        }
+    }
+
+    fn next_outcome(&self, method: &str, params: &serde_json::Value) -> Option<RecordedOutcome> {
+        let mut interactions = self.interactions.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let (_, _, outcomes) = interactions.iter_mut()
+            .find(|(recorded_method, recorded_params, _)| recorded_method == method && recorded_params == params)?;
+        if outcomes.len() > 1 {
+            outcomes.pop_front()
+        } else {
+            outcomes.front().cloned()
+        }
+    }
+}
+
+impl RpcTransport for ReplayTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>> {
+        let params = request.body.get("params").cloned().unwrap_or_default();
+        let result = match self.next_outcome(request.method(), &params) {
+            Some(outcome) => outcome.replay(&request),
+            None => Err(BlockProductionError::config_error(
+                &format!("No recorded interaction for {} with params {params}", request.method()),
+                Some("cassette"),
+                Some("Re-record the cassette with the same requests"),
+            )),
+        };
+        Box::pin(async move { result })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::MockTransport;
+    use reqwest::header::HeaderMap;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let path = std::env::temp_dir().join(format!("bp-cassette-{}.jsonl", std::process::id()));
+        let inner = Arc::new(
+            MockTransport::new()
+                .respond_result("getSlot", json!(100))
+                .respond_result("getSlot", json!(101)),
+        );
+        let recorder = RecordingTransport::new(inner, &path).unwrap();
+        let headers = HeaderMap::new();
+        let request = |body| TransportRequest {
+            endpoint: "http://localhost",
+            headers: &headers,
+            body,
+            timeout: Duration::from_secs(1),
+            max_response_bytes: None,
+        };
+
+        let get_slot = json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot", "params": [{"commitment": "finalized"}]});
+        recorder.send(request(&get_slot)).await.unwrap();
+        recorder.send(request(&get_slot)).await.unwrap();
+
+        let replay = ReplayTransport::from_file(&path).unwrap();
+        let result = |response: TransportResponse| {
+            serde_json::from_slice::<serde_json::Value>(&response.body).unwrap()["result"].clone()
+        };
+
+        // Same params in any id: served in recorded order, then the last one repeats
+        let replayed_request = json!({"jsonrpc": "2.0", "id": 7, "method": "getSlot", "params": [{"commitment": "finalized"}]});
+        assert_eq!(result(replay.send(request(&replayed_request)).await.unwrap()), 100);
+        assert_eq!(result(replay.send(request(&replayed_request)).await.unwrap()), 101);
+        assert_eq!(result(replay.send(request(&replayed_request)).await.unwrap()), 101);
+
+        let other_params = json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot", "params": [{"commitment": "confirmed"}]});
+        let error = replay.send(request(&other_params)).await.unwrap_err();
+        assert!(matches!(error, BlockProductionError::Config { .. }));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_recorded_errors_and_headers() {
+        let headers = HeaderMap::new();
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot"});
+        let request = TransportRequest {
+            endpoint: "http://localhost",
+            headers: &headers,
+            body: &body,
+            timeout: Duration::from_secs(1),
+            max_response_bytes: Some(10),
+        };
+        let round_trip = |result: Result<TransportResponse>| {
+            let outcome = RecordedOutcome::from_result(&result);
+            let outcome: RecordedOutcome = serde_json::from_str(&serde_json::to_string(&outcome).unwrap()).unwrap();
+            outcome.replay(&request)
+        };
+
+        let timeout = round_trip(Err(BlockProductionError::Timeout {
+            duration: Duration::from_secs(1),
+            operation: "RPC getSlot request".to_string(),
+            timeout_type: TimeoutType::Connection,
+        }));
+        assert!(matches!(timeout, Err(BlockProductionError::Timeout { timeout_type: TimeoutType::Connection, .. })));
+
+        let too_large = round_trip(Err(request.response_too_large(20)));
+        assert!(matches!(too_large, Err(BlockProductionError::ResponseTooLarge { limit: 10, received: 20, .. })));
+
+        let config = round_trip(Err(BlockProductionError::config_error("Bad proxy", Some("proxy"), None)));
+        assert!(matches!(config, Err(BlockProductionError::Config { field: Some(ref field), .. }) if field == "proxy"));
+
+        let recorded = RecordedOutcome::from_result(&Err(replayed_http_error("error decoding response body", &request)));
+        assert!(matches!(recorded, RecordedOutcome::Http { .. }));
+        let http = RecordedOutcome::Http { message: "error decoding response body".to_string() }.replay(&request);
+        match http {
+            Err(BlockProductionError::Http { context: Some(context), .. }) => {
+                assert!(context.contains("error decoding response body"));
+            }
+            other => panic!("expected an HTTP error, got {other:?}"),
+        }
+
+        // Only the headers the client reads are kept
+        let response = TransportResponse::new(429, b"{}".to_vec())
+            .with_header("retry-after", "2")
+            .with_header("set-cookie", "session=secret")
+            .with_header("x-request-id", "abc");
+        let replayed = round_trip(Ok(response)).unwrap();
+        assert_eq!(replayed.headers.get("retry-after").unwrap(), "2");
+        assert!(replayed.headers.get("set-cookie").is_none());
+        assert!(replayed.headers.get("x-request-id").is_none());
+    }
+}
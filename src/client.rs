@@ -1,27 +1,419 @@
 use crate::{
-    config::ClientConfig,
-    error::{BlockProductionError, Result, TimeoutType, AuthErrorType},
-    types::{BlockProductionData, BlockProductionRequest, BlockProductionDataDebug, ResponseMetadata, ValidatorSkipRate, SlotRange, RpcResponse, SkipRateStatistics, SkipRateDistribution, DistributionBucket, PercentileData, DistributionPlotData, NetworkHealthSummary, NetworkStatus, DashboardMetrics, MetricCard, TrendDirection, NetworkAlert, AlertSeverity, AlertCategory, ValidatorPerformanceSnapshot, ValidatorPerformanceCategory},
+    query::{SortOrder, ValidatorSortField},
+    transport::{ReqwestTransport, RpcTransport, TransportRequest},
+    cassette::{CassetteMode, RecordingTransport, ReplayTransport},
+    config::{parse_retry_after, AdaptiveRateLimit, CircuitBreakerConfig, ClientConfig, EndpointConfig, HttpVersionPreference, ProviderQuota, ProxyConfig, RetryPolicy, SharedRateLimiter, TlsConfig},
+    error::{BlockProductionError, ErrorExt, Result, TimeoutType, AuthErrorType},
+    secret::{redact_url, Secret},
+    types::{BlockProductionData, BlockProductionRequest, BlockProductionDataDebug, ResponseMetadata, ValidatorSkipRate, SlotRange, RpcResponse, SkipRateStatistics, SkipRateDistribution, DistributionBucket, PercentileData, DistributionPlotData, NetworkHealthSummary, NetworkStatus, DashboardMetrics, MetricCard, TrendDirection, NetworkAlert, AlertSeverity, AlertCategory, ValidatorPerformanceSnapshot, ValidatorPerformanceCategory, EpochInfo, EpochSchedule, EpochContext, StakeWeightedStatistics, VoteAccounts, ValidatorInfo, CONFIG_PROGRAM_ID, ClusterNode, VersionSkipRateStatistics, EpochHistory, UnavailableEpoch, BlockProductionValue, RangeCoverage, FailedRangeChunk, EpochProjection, ValidatorSkipRateProjection, ClusterSkipRateProjection, EndpointDiagnostics, RpcCallDiagnostic, RpcVersionInfo, SolanaCluster, EndpointHealth, CircuitState, RequestTelemetry, RpcAttempt, ConsensusReport, ConsensusResult, ProviderCounts, ProviderResponse, ValidatorDisagreement},
 };
 use chrono::{DateTime, Utc};
-use reqwest::Client;
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use serde_json::json;
+use serde_json::value::RawValue;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::time::timeout;
 use tracing::{debug, error, info, warn, trace, instrument};
 
+/// Maximum slot span accepted by a single getBlocks call
+const MAX_GET_BLOCKS_RANGE: u64 = 500_000;
+
+/// Pseudo-observations of the cluster skip rate blended into each validator's rate,
+/// so validators with few observed slots are not projected at 0% or 100%
+const PROJECTION_PRIOR_SLOTS: f64 = 20.0;
+
+/// z-score of the 95% projection band
+const PROJECTION_Z_SCORE: f64 = 1.96;
+
+/// Cached validator-info metadata with the time it was fetched
+type ValidatorInfoCache = Option<(Instant, Arc<HashMap<String, ValidatorInfo>>)>;
+
+/// Consecutive failures after which an endpoint is moved to the back of the pool
+const ENDPOINT_UNHEALTHY_AFTER_FAILURES: u32 = 3;
+
+/// How long an unhealthy endpoint is avoided before it is tried in priority order again
+const ENDPOINT_RECOVERY_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// Slots subtracted from the current slot when pinning a consensus range, so
+/// providers lagging slightly behind can still serve it
+const CONSENSUS_SLOT_MARGIN: u64 = 150;
+
+/// Weight of the newest sample in the endpoint latency moving average
+const ENDPOINT_LATENCY_SMOOTHING: f64 = 0.2;
+
+/// Milliseconds of a duration, saturating at `u64::MAX`
+fn duration_ms(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Block production data cached per request
+#[derive(Debug)]
+struct CachedResponse {
+    fetched_at: Instant,
+    data: BlockProductionData,
+    answered_by: String,
+}
+
+/// How a successful response body is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseMode {
+    /// Buffer the whole body, then decode
+    Buffered,
+    /// Decode a getBlockProduction response while it streams in
+    StreamBlockProduction,
+}
+
+/// Response body as returned by the transport
+enum ResponseBody {
+    Buffered(Vec<u8>),
+    Streaming(BoxStream<'static, Result<Vec<u8>>>),
+}
+
+/// Body of a successful JSON-RPC response, decoded into typed values on demand
+///
+/// Buffered bodies are validated and checked for a JSON-RPC `error` without
+/// building a `serde_json::Value` tree; callers deserialize straight into their types.
+#[derive(Debug)]
+enum RpcBody {
+    /// Raw response bytes
+    Buffered(Vec<u8>),
+    /// getBlockProduction result decoded while the body streamed in
+    BlockProduction {
+        value: BlockProductionValue,
+        context_slot: Option<u64>,
+    },
+}
+
+/// Top-level fields of a JSON-RPC response, borrowed from the body
+#[derive(Deserialize)]
+struct RpcEnvelope<'a> {
+    #[serde(default, borrow, deserialize_with = "present_raw_value")]
+    result: Option<&'a RawValue>,
+    #[serde(default, borrow)]
+    error: Option<&'a RawValue>,
+}
+
+/// Deserialize a field that is present (even as `null`) into `Some`
+fn present_raw_value<'de, D>(deserializer: D) -> std::result::Result<Option<&'de RawValue>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    <&RawValue>::deserialize(deserializer).map(Some)
+}
+
+/// `result.context.slot` of a response, skipping everything else
+#[derive(Deserialize)]
+struct ContextSlot {
+    context: Option<SlotContext>,
+}
+
+#[derive(Deserialize)]
+struct SlotContext {
+    slot: u64,
+}
+
+/// getBlockProduction response decoded by the streaming decoder
+#[derive(Deserialize)]
+struct StreamedEnvelope {
+    #[serde(default)]
+    result: Option<StreamedResult>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct StreamedResult {
+    context: Option<SlotContext>,
+    value: BlockProductionValue,
+}
+
+/// Chunks buffered between the body stream and the streaming decoder
+const STREAMING_DECODE_BUFFERED_CHUNKS: usize = 16;
+
+/// Blocking `Read` over body chunks sent from the async side
+struct ChunkReader {
+    chunks: tokio::sync::mpsc::Receiver<Vec<u8>>,
+    current: Vec<u8>,
+    position: usize,
+}
+
+impl ChunkReader {
+    const fn new(chunks: tokio::sync::mpsc::Receiver<Vec<u8>>) -> Self {
+        Self {
+            chunks,
+            current: Vec::new(),
+            position: 0,
+        }
+    }
+}
+
+impl std::io::Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position >= self.current.len() {
+            match self.chunks.blocking_recv() {
+                Some(chunk) => {
+                    self.current = chunk;
+                    self.position = 0;
+                }
+                None => return Ok(0),
+            }
+        }
+        let available = &self.current[self.position..];
+        let count = available.len().min(buf.len());
+        buf[..count].copy_from_slice(&available[..count]);
+        self.position += count;
+        Ok(count)
+    }
+}
+
+impl RpcBody {
+    fn buffered(&self) -> Result<&[u8]> {
+        match self {
+            Self::Buffered(bytes) => Ok(bytes),
+            Self::BlockProduction { .. } => Err(BlockProductionError::Internal {
+                message: "Streamed responses are decoded as getBlockProduction only".to_string(),
+                location: Some("RpcBody::buffered".to_string()),
+                debug_context: None,
+            }),
+        }
+    }
+
+    /// The getBlockProduction `result.value`
+    fn into_block_production_value(self) -> Result<BlockProductionValue> {
+        match self {
+            Self::Buffered(bytes) => Self::decode_block_production_value(&bytes),
+            Self::BlockProduction { value, .. } => Ok(value),
+        }
+    }
+
+    /// Typed decode of a buffered getBlockProduction body
+    fn decode_block_production_value(bytes: &[u8]) -> Result<BlockProductionValue> {
+        Ok(serde_json::from_slice::<RpcResponse>(bytes)?.result.value)
+    }
+
+    /// Deserialize the `result` field
+    fn result<T: DeserializeOwned>(&self, method: &str) -> Result<T> {
+        let envelope: RpcEnvelope<'_> = serde_json::from_slice(self.buffered()?)?;
+        let result = envelope.result.ok_or_else(|| BlockProductionError::ResponseParsing {
+            reason: format!("{method} response is missing the 'result' field"),
+            response_sample: None,
+            expected_structure: Some("Valid JSON object with 'result' field".to_string()),
+        })?;
+        Ok(serde_json::from_str(result.get())?)
+    }
+
+    fn has_result(&self) -> bool {
+        match self {
+            Self::Buffered(bytes) => serde_json::from_slice::<RpcEnvelope<'_>>(bytes)
+                .is_ok_and(|envelope| envelope.result.is_some()),
+            Self::BlockProduction { .. } => true,
+        }
+    }
+
+    fn context_slot(&self) -> Option<u64> {
+        match self {
+            Self::Buffered(bytes) => {
+                let envelope: RpcEnvelope<'_> = serde_json::from_slice(bytes).ok()?;
+                let result: ContextSlot = serde_json::from_str(envelope.result?.get()).ok()?;
+                result.context.map(|context| context.slot)
+            }
+            Self::BlockProduction { context_slot, .. } => *context_slot,
+        }
+    }
+
+    /// The body as a JSON tree, for debug output
+    fn to_value(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::from_slice(self.buffered()?)?)
+    }
+
+    fn sample(&self) -> String {
+        match self {
+            Self::Buffered(bytes) => String::from_utf8_lossy(&bytes[..bytes.len().min(200)]).into_owned(),
+            Self::BlockProduction { .. } => String::new(),
+        }
+    }
+}
+
 /// Client for fetching Solana block production data and calculating skip rates
 #[derive(Debug)]
 pub struct BlockProductionClient {
     config: ClientConfig,
-    http_client: Client,
+    transport: Arc<dyn RpcTransport>,
+    endpoints: Vec<RpcEndpoint>,
+    validator_info_cache: Mutex<ValidatorInfoCache>,
+    response_cache: Mutex<HashMap<BlockProductionRequest, CachedResponse>>,
+}
+
+/// An endpoint of the pool (primary first, then fallbacks) with its health record
+///
+/// `label` is the URL with credentials redacted and is what logs, errors and
+/// response metadata report.
+#[derive(Debug)]
+struct RpcEndpoint {
+    url: Secret,
+    label: String,
+    headers: reqwest::header::HeaderMap,
+    health: Mutex<EndpointHealthState>,
+}
+
+#[derive(Debug, Default)]
+struct EndpointHealthState {
+    successes: u64,
+    failures: u64,
+    consecutive_failures: u32,
+    average_latency_ms: Option<f64>,
+    last_failure: Option<Instant>,
+    circuit: CircuitState,
+    circuit_opened_at: Option<Instant>,
+    probe_started_at: Option<Instant>,
+}
+
+impl EndpointHealthState {
+    /// Laplace-smoothed success rate, so untried endpoints start at 50%
+    #[allow(clippy::cast_precision_loss)]
+    fn health_score(&self) -> f64 {
+        (self.successes as f64 + 1.0) / ((self.successes + self.failures) as f64 + 2.0)
+    }
+
+    fn is_healthy(&self) -> bool {
+        self.consecutive_failures < ENDPOINT_UNHEALTHY_AFTER_FAILURES
+            || self.last_failure.map_or(true, |at| at.elapsed() >= ENDPOINT_RECOVERY_COOLDOWN)
+    }
+
+    /// Time until an open circuit admits a probe request
+    fn circuit_retry_in(&self, breaker: &CircuitBreakerConfig) -> Option<Duration> {
+        match self.circuit {
+            CircuitState::Open => self.circuit_opened_at
+                .map(|at| breaker.cooldown.saturating_sub(at.elapsed())),
+            CircuitState::Closed | CircuitState::HalfOpen => None,
+        }
+    }
+}
+
+impl RpcEndpoint {
+    fn new(url: &str, headers: &HashMap<String, Secret>) -> Result<Self> {
+        Ok(Self {
+            url: Secret::new(url),
+            label: redact_url(url),
+            headers: Self::build_header_map(headers)?,
+            health: Mutex::new(EndpointHealthState::default()),
+        })
+    }
+
+    fn build_header_map(headers: &HashMap<String, Secret>) -> Result<reqwest::header::HeaderMap> {
+        let mut header_map = reqwest::header::HeaderMap::new();
+
+        // Add custom headers with validation
+        for (key, value) in headers {
+            trace!(header_name = key, "Adding custom header");
+
+            let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
+                .map_err(|e| BlockProductionError::config_error(
+                    &format!("Invalid header name '{key}': {e}"),
+                    Some("headers"),
+                    Some("Use valid HTTP header names (alphanumeric and hyphens)"),
+                ))?;
+
+            let mut header_value = reqwest::header::HeaderValue::from_str(value.expose())
+                .map_err(|e| BlockProductionError::config_error(
+                    &format!("Invalid value for header '{key}': {e}"),
+                    Some("headers"),
+                    Some("Header values must be valid ASCII"),
+                ))?;
+            header_value.set_sensitive(true);
+
+            header_map.insert(header_name, header_value);
+        }
+
+        Ok(header_map)
+    }
+
+    fn health(&self) -> std::sync::MutexGuard<'_, EndpointHealthState> {
+        self.health.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Check the circuit breaker before calling the endpoint
+    ///
+    /// An open circuit whose cool-down has elapsed turns half-open and admits this
+    /// request as the probe. A probe that never reported back (e.g. its future was
+    /// dropped) is replaced after another cool-down.
+    fn acquire_circuit(&self, breaker: &CircuitBreakerConfig) -> Result<()> {
+        let mut health = self.health();
+        let cooldown_elapsed = |at: Option<Instant>| at.map_or(true, |at| at.elapsed() >= breaker.cooldown);
+
+        let retry_in = match health.circuit {
+            CircuitState::Closed => return Ok(()),
+            CircuitState::Open if cooldown_elapsed(health.circuit_opened_at) => None,
+            CircuitState::HalfOpen if cooldown_elapsed(health.probe_started_at) => None,
+            CircuitState::Open => health.circuit_retry_in(breaker),
+            CircuitState::HalfOpen => health.probe_started_at
+                .map(|at| breaker.cooldown.saturating_sub(at.elapsed())),
+        };
+
+        match retry_in {
+            None => {
+                debug!(endpoint = %self.label, "Circuit half-open, probing endpoint");
+                health.circuit = CircuitState::HalfOpen;
+                health.probe_started_at = Some(Instant::now());
+                Ok(())
+            }
+            Some(retry_in) => Err(BlockProductionError::CircuitOpen {
+                endpoint: self.label.clone(),
+                consecutive_failures: health.consecutive_failures,
+                retry_in,
+            }),
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn record_success(&self, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        let mut health = self.health();
+        health.successes += 1;
+        health.consecutive_failures = 0;
+        health.average_latency_ms = Some(health.average_latency_ms.map_or(latency_ms, |average| {
+            average + ENDPOINT_LATENCY_SMOOTHING * (latency_ms - average)
+        }));
+        if health.circuit != CircuitState::Closed {
+            info!(endpoint = %self.label, "Circuit closed");
+        }
+        health.circuit = CircuitState::Closed;
+        health.circuit_opened_at = None;
+        health.probe_started_at = None;
+    }
+
+    fn record_failure(&self, breaker: Option<&CircuitBreakerConfig>) {
+        let mut health = self.health();
+        health.failures += 1;
+        health.consecutive_failures += 1;
+        health.last_failure = Some(Instant::now());
+
+        let Some(breaker) = breaker else { return };
+        let trips = health.circuit == CircuitState::HalfOpen
+            || health.consecutive_failures >= breaker.failure_threshold;
+        if trips && health.circuit != CircuitState::Open {
+            warn!(
+                endpoint = %self.label,
+                consecutive_failures = health.consecutive_failures,
+                cooldown_ms = breaker.cooldown.as_millis(),
+                "Circuit opened"
+            );
+            health.circuit = CircuitState::Open;
+            health.circuit_opened_at = Some(Instant::now());
+            health.probe_started_at = None;
+        }
+    }
 }
 
 impl BlockProductionClient {
     /// Create a new client with default configuration
-    #[instrument(fields(endpoint = rpc_endpoint))]
+    #[instrument(skip(rpc_endpoint), fields(endpoint = %redact_url(rpc_endpoint)))]
     pub fn new(rpc_endpoint: &str) -> Result<Self> {
-        info!(endpoint = rpc_endpoint, "Creating new BlockProductionClient");
+        info!("Creating new BlockProductionClient");
         
         // Validate RPC endpoint format
         if rpc_endpoint.is_empty() {
@@ -48,61 +440,57 @@ impl BlockProductionClient {
     }
 
     /// Create a client from configuration
-    #[instrument(skip(config), fields(endpoint = %config.rpc_endpoint))]
+    #[instrument(skip(config), fields(endpoint = %redact_url(&config.rpc_endpoint)))]
     pub fn from_config(config: ClientConfig) -> Result<Self> {
         debug!(
-            endpoint = %config.rpc_endpoint,
             timeout_ms = config.timeout.as_millis(),
             retry_attempts = config.retry_attempts,
             "Creating client from configuration"
         );
 
-        let mut headers = reqwest::header::HeaderMap::new();
-        
-        // Add custom headers with validation
-        for (key, value) in &config.headers {
-            trace!(header_name = key, "Adding custom header");
-            
-            let header_name = reqwest::header::HeaderName::from_bytes(key.as_bytes())
-                .map_err(|e| BlockProductionError::config_error(
-                    &format!("Invalid header name '{key}': {e}"),
-                    Some("headers"),
-                    Some("Use valid HTTP header names (alphanumeric and hyphens)"),
-                ))?;
-                
-            let header_value = reqwest::header::HeaderValue::from_str(value)
-                .map_err(|e| BlockProductionError::config_error(
-                    &format!("Invalid header value '{value}': {e}"),
-                    Some("headers"),
-                    Some("Header values must be valid ASCII"),
-                ))?;
-                
-            headers.insert(header_name, header_value);
+        // Headers are sent per endpoint so fallbacks can use their own credentials
+        let mut endpoints = vec![RpcEndpoint::new(&config.rpc_endpoint, &config.headers)?];
+        for fallback in &config.fallback_endpoints {
+            endpoints.push(RpcEndpoint::new(&fallback.url, &fallback.headers)?);
         }
 
-        debug!(header_count = headers.len(), "Added custom headers");
+        debug!(
+            header_count = config.headers.len(),
+            fallback_count = config.fallback_endpoints.len(),
+            "Configured RPC endpoints"
+        );
 
-        let http_client = Client::builder()
-            .timeout(config.timeout)
-            .default_headers(headers)
-            .user_agent("blocks-production-lib/0.1.0")
-            .pool_idle_timeout(Duration::from_secs(90))
-            .pool_max_idle_per_host(10)
-            .build()
-            .map_err(|e| BlockProductionError::config_error(
-                &format!("Failed to create HTTP client: {e}"),
-                None,
-                Some("Check timeout and header configuration"),
-            ))?;
+        let transport: Arc<dyn RpcTransport> = match (&config.cassette, &config.transport) {
+            (Some(CassetteMode::Replay(path)), _) => {
+                info!(cassette = %path.display(), "Replaying RPC traffic from cassette");
+                Arc::new(ReplayTransport::from_file(path)?)
+            }
+            (cassette, transport) => {
+                let transport: Arc<dyn RpcTransport> = match transport {
+                    Some(transport) => Arc::clone(transport),
+                    None => Arc::new(ReqwestTransport::from_config(&config)?),
+                };
+                match cassette {
+                    Some(CassetteMode::Record(path)) => {
+                        info!(cassette = %path.display(), "Recording RPC traffic to cassette");
+                        Arc::new(RecordingTransport::new(transport, path)?)
+                    }
+                    _ => transport,
+                }
+            }
+        };
 
         info!(
-            endpoint = %config.rpc_endpoint,
+            endpoint = %endpoints[0].label,
             "Successfully created BlockProductionClient"
         );
 
         Ok(Self {
             config,
-            http_client,
+            transport,
+            endpoints,
+            validator_info_cache: Mutex::new(None),
+            response_cache: Mutex::new(HashMap::new()),
         })
     }
 
@@ -112,8 +500,23 @@ impl BlockProductionClient {
         ClientBuilder::new()
     }
 
+    /// Decode a buffered getBlockProduction body the way a request attempt does:
+    /// envelope check for an RPC error, then typed decode of `result.value`
+    ///
+    /// Only public for the decoding benchmark.
+    #[doc(hidden)]
+    pub fn decode_block_production_body(body: &[u8]) -> Result<BlockProductionValue> {
+        let envelope: RpcEnvelope<'_> = serde_json::from_slice(body)?;
+        if let Some(error) = envelope.error {
+            let error: serde_json::Value = serde_json::from_str(error.get())?;
+            let raw_response = String::from_utf8_lossy(body).into_owned();
+            return Err(Self::rpc_error(&error, "getBlockProduction", 1, raw_response, &mut Vec::new()));
+        }
+        RpcBody::decode_block_production_value(body)
+    }
+
     /// Test RPC endpoint connectivity
-    #[instrument(skip(self), fields(endpoint = %self.config.rpc_endpoint))]
+    #[instrument(skip(self), fields(endpoint = %self.endpoints[0].label))]
     pub async fn test_connection(&self) -> Result<bool> {
         info!("Testing RPC endpoint connectivity");
         
@@ -125,10 +528,11 @@ impl BlockProductionClient {
 
         let start_time = Instant::now();
         
-        match self.make_rpc_request(request).await {
+        // Probe the configured endpoint itself rather than whichever one the pool picks
+        match self.make_primary_rpc_request(&request).await {
             Ok(response) => {
                 let duration = start_time.elapsed();
-                let has_result = response.get("result").is_some();
+                let has_result = response.has_result();
                 
                 if has_result {
                     info!(
@@ -138,7 +542,7 @@ impl BlockProductionClient {
                 } else {
                     warn!(
                         response_time_ms = duration.as_millis(),
-                        response = %response,
+                        response = %response.sample(),
                         "RPC endpoint responded but without expected result"
                     );
                 }
@@ -154,7 +558,7 @@ impl BlockProductionClient {
                 
                 // Return a more informative error for connection tests
                 Err(BlockProductionError::ConnectionFailed {
-                    endpoint: self.config.rpc_endpoint.clone(),
+                    endpoint: self.endpoints[0].label.clone(),
                     source: Box::new(e),
                     endpoint_reachable: Some(false),
                 })
@@ -162,6 +566,120 @@ impl BlockProductionClient {
         }
     }
 
+    /// Health of each endpoint in the pool, primary first
+    #[allow(clippy::cast_precision_loss)]
+    pub fn endpoint_health(&self) -> Vec<EndpointHealth> {
+        self.endpoints.iter()
+            .enumerate()
+            .map(|(index, endpoint)| {
+                let health = endpoint.health();
+                let requests = health.successes + health.failures;
+                EndpointHealth {
+                    endpoint: endpoint.label.clone(),
+                    is_primary: index == 0,
+                    healthy: health.is_healthy(),
+                    successes: health.successes,
+                    failures: health.failures,
+                    consecutive_failures: health.consecutive_failures,
+                    success_rate_percent: (requests > 0)
+                        .then(|| health.successes as f64 / requests as f64 * 100.0),
+                    average_latency_ms: health.average_latency_ms,
+                    circuit_state: health.circuit,
+                    circuit_retry_in_ms: self.config.circuit_breaker.as_ref()
+                        .and_then(|breaker| health.circuit_retry_in(breaker))
+                        .map(duration_ms),
+                }
+            })
+            .collect()
+    }
+
+    /// Close every endpoint's circuit breaker, e.g. after fixing a known outage
+    pub fn reset_circuit_breakers(&self) {
+        for endpoint in &self.endpoints {
+            let mut health = endpoint.health();
+            health.consecutive_failures = 0;
+            health.circuit = CircuitState::Closed;
+            health.circuit_opened_at = None;
+            health.probe_started_at = None;
+        }
+    }
+
+    /// Collect health, version, slot and ledger coverage diagnostics for the endpoint
+    ///
+    /// Individual call failures are recorded in `calls` rather than failing the
+    /// whole diagnosis.
+    pub async fn diagnose_endpoint(&self) -> Result<EndpointDiagnostics> {
+        self.diagnose_endpoint_with_reference(None).await
+    }
+
+    /// Diagnose the endpoint and measure its slot lag against a reference endpoint
+    #[instrument(skip(self, reference_endpoint), fields(endpoint = %self.endpoints[0].label))]
+    pub async fn diagnose_endpoint_with_reference(
+        &self,
+        reference_endpoint: Option<&str>,
+    ) -> Result<EndpointDiagnostics> {
+        info!("Diagnosing RPC endpoint");
+        let mut calls = Vec::new();
+
+        let health: Option<String> = self.diagnostic_call(&mut calls, "getHealth").await;
+        let version: Option<RpcVersionInfo> = self.diagnostic_call(&mut calls, "getVersion").await;
+        let slot: Option<u64> = self.diagnostic_call(&mut calls, "getSlot").await;
+        let genesis_hash: Option<String> = self.diagnostic_call(&mut calls, "getGenesisHash").await;
+        let minimum_ledger_slot: Option<u64> = self.diagnostic_call(&mut calls, "minimumLedgerSlot").await;
+        let first_available_block: Option<u64> = self.diagnostic_call(&mut calls, "getFirstAvailableBlock").await;
+
+        let (reference_slot, reference_call) = match reference_endpoint {
+            Some(reference) => {
+                // Same headers, retries and transport (proxy, TLS, cassette) as this client
+                let reference_client = Self::from_config(ClientConfig {
+                    rpc_endpoint: reference.to_string(),
+                    fallback_endpoints: Vec::new(),
+                    transport: Some(Arc::clone(&self.transport)),
+                    cassette: None,
+                    ..self.config.clone()
+                })?;
+                let mut reference_calls = Vec::new();
+                let reference_slot: Option<u64> = reference_client
+                    .diagnostic_call(&mut reference_calls, "getSlot")
+                    .await;
+                let reference_call = reference_calls.pop().map(|mut call| {
+                    call.method = format!("getSlot (reference {})", redact_url(reference));
+                    call
+                });
+                (reference_slot, reference_call)
+            }
+            None => (None, None),
+        };
+
+        #[allow(clippy::cast_possible_wrap)]
+        let slot_lag = match (slot, reference_slot) {
+            (Some(slot), Some(reference_slot)) => Some(reference_slot as i64 - slot as i64),
+            _ => None,
+        };
+
+        let cluster = genesis_hash
+            .as_deref()
+            .map_or(SolanaCluster::Unknown, SolanaCluster::from_genesis_hash);
+
+        Ok(EndpointDiagnostics {
+            rpc_endpoint: self.endpoints[0].label.clone(),
+            healthy: health.as_deref() == Some("ok"),
+            version: version.as_ref().map(|v| v.solana_core.clone()),
+            feature_set: version.and_then(|v| v.feature_set),
+            genesis_hash,
+            cluster,
+            slot,
+            minimum_ledger_slot,
+            first_available_block,
+            reference_endpoint: reference_endpoint.map(redact_url),
+            reference_slot,
+            slot_lag,
+            calls,
+            reference_call,
+            checked_at: Utc::now(),
+        })
+    }
+
     /// Fetch block production data for all validators
     pub async fn fetch_block_production(&self) -> Result<BlockProductionData> {
         self.fetch_block_production_with_params(BlockProductionRequest::default())
@@ -169,14 +687,27 @@ impl BlockProductionClient {
     }
 
     /// Fetch block production data with specific parameters
+    ///
+    /// Served from the response cache when `response_cache_ttl` is set and the same
+    /// request was fetched within the TTL.
     pub async fn fetch_block_production_with_params(
         &self,
         params: BlockProductionRequest,
     ) -> Result<BlockProductionData> {
-        let start_time = Instant::now();
-        let rpc_response = self.fetch_raw_block_production(&params).await?;
+        if let Some((data, _, _)) = self.cached_response(&params) {
+            return Ok(data);
+        }
+
+        let request = Self::build_rpc_request(&params);
+        let mut telemetry = RequestTelemetry::new("getBlockProduction");
+        let (body, answered_by) = self
+            .make_pooled_rpc_request(&request, self.block_production_mode(), &mut telemetry)
+            .await?;
         
-        let production_data = Self::process_block_production_response(rpc_response, start_time)?;
+        let mut production_data = Self::build_block_production_data(body.into_block_production_value()?)?;
+        production_data.telemetry = Some(telemetry);
+        self.apply_request_options(&mut production_data, &params).await?;
+        self.cache_response(params, &production_data, answered_by);
         Ok(production_data)
     }
 
@@ -187,44 +718,218 @@ impl BlockProductionClient {
     ) -> Result<BlockProductionDataDebug> {
         let start_time = Instant::now();
         let request_json = Self::build_rpc_request(&params);
-        let rpc_response = self.fetch_raw_block_production(&params).await?;
+
+        if let Some((production_data, answered_by, age)) = self.cached_response(&params) {
+            return Ok(BlockProductionDataDebug {
+                production_data,
+                raw_rpc_data: serde_json::Value::Null,
+                request_params: request_json,
+                response_metadata: ResponseMetadata {
+                    rpc_endpoint: answered_by,
+                    response_time_ms: duration_ms(start_time.elapsed()),
+                    retry_attempts: 0,
+                    rate_limited: false,
+                    cache_hit: true,
+                    cache_age_ms: Some(duration_ms(age)),
+                    telemetry: None,
+                },
+            });
+        }
+
+        let mut telemetry = RequestTelemetry::new("getBlockProduction");
+        let (body, answered_by) = self
+            .make_pooled_rpc_request(&request_json, ResponseMode::Buffered, &mut telemetry)
+            .await?;
         
-        let production_data = Self::process_block_production_response(rpc_response.clone(), start_time)?;
-        #[allow(clippy::cast_possible_truncation)]
-        let response_time = start_time.elapsed().as_millis() as u64;
+        // The raw tree is only built here, for debug output
+        let raw_rpc_data = body.to_value()?;
+        let mut production_data = Self::build_block_production_data(body.into_block_production_value()?)?;
+        production_data.telemetry = Some(telemetry.clone());
+        self.apply_request_options(&mut production_data, &params).await?;
+        self.cache_response(params, &production_data, answered_by);
+        let response_time = duration_ms(start_time.elapsed());
 
         Ok(BlockProductionDataDebug {
             production_data,
-            raw_rpc_data: rpc_response,
+            raw_rpc_data,
             request_params: request_json,
             response_metadata: ResponseMetadata {
-                rpc_endpoint: self.config.rpc_endpoint.clone(),
+                rpc_endpoint: answered_by.to_string(),
                 response_time_ms: response_time,
-                retry_attempts: 0, // TODO: Track actual retry attempts
-                rate_limited: false, // TODO: Track if rate limiting was applied
+                retry_attempts: telemetry.retries(),
+                rate_limited: telemetry.rate_limited,
+                cache_hit: false,
+                cache_age_ms: None,
+                telemetry: Some(telemetry),
             },
         })
     }
 
+    /// Query the same range against every endpoint in the pool and compare the results
+    ///
+    /// Requires at least one fallback endpoint. Without an explicit range the current
+    /// epoch up to `CONSENSUS_SLOT_MARGIN` slots before the current slot is used, so
+    /// every provider is asked for identical slots. Counts a strict majority of
+    /// providers agree on are used; if any validator or the range lacks a majority
+    /// the result is `Unresolved` with the disagreement report.
+    pub async fn fetch_block_production_consensus(
+        &self,
+        params: BlockProductionRequest,
+    ) -> Result<ConsensusResult> {
+        if self.endpoints.len() < 2 {
+            return Err(BlockProductionError::config_error(
+                "Consensus mode needs at least two endpoints",
+                Some("fallback_endpoints"),
+                Some("Add fallback endpoints with ClientBuilder::fallback_endpoint"),
+            ));
+        }
+
+        let requested_range = match &params.range {
+            Some(range) => range.clone(),
+            None => {
+                let epoch_info = self.current_epoch_info().await?;
+                let last_slot = epoch_info
+                    .absolute_slot
+                    .saturating_sub(CONSENSUS_SLOT_MARGIN)
+                    .max(epoch_info.first_slot());
+                SlotRange { first_slot: epoch_info.first_slot(), last_slot }
+            }
+        };
+
+        let request = Self::build_rpc_request(&BlockProductionRequest {
+            range: Some(requested_range.clone()),
+            ..params.clone()
+        });
+
+        info!(
+            first_slot = requested_range.first_slot,
+            last_slot = requested_range.last_slot,
+            providers = self.endpoints.len(),
+            "Fetching block production consensus"
+        );
+
+        let mut telemetry = RequestTelemetry::new("getBlockProduction");
+        let responses: Vec<(String, Result<BlockProductionValue>)> = stream::iter(0..self.endpoints.len())
+            .map(|index| {
+                let request = &request;
+                async move {
+                    let mut telemetry = RequestTelemetry::new("getBlockProduction");
+                    let start = Instant::now();
+                    let result = self
+                        .call_endpoint(index, request, self.block_production_mode(), &mut telemetry)
+                        .await
+                        .and_then(RpcBody::into_block_production_value);
+                    telemetry.total_duration_ms = duration_ms(start.elapsed());
+                    (self.endpoints[index].label.clone(), result, telemetry)
+                }
+            })
+            .buffered(self.config.max_concurrent_requests.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .map(|(url, result, provider_telemetry)| {
+                telemetry.merge(provider_telemetry);
+                (url, result)
+            })
+            .collect();
+
+        // Nothing to compare if every provider failed
+        if !responses.iter().any(|(_, result)| result.is_ok()) {
+            return Err(responses
+                .into_iter()
+                .find_map(|(_, result)| result.err())
+                .unwrap_or_else(|| BlockProductionError::Internal {
+                    message: "No provider was queried for consensus".to_string(),
+                    location: Some("fetch_block_production_consensus".to_string()),
+                    debug_context: None,
+                }));
+        }
+
+        let (reconciled, report) = Self::reconcile_provider_responses(requested_range, responses);
+
+        if !report.is_unanimous() {
+            warn!(
+                disagreements = report.disagreements.len(),
+                ranges_match = report.ranges_match,
+                "Providers returned inconsistent block production data"
+            );
+        }
+
+        match reconciled {
+            Some(value) => {
+                let mut data = Self::build_block_production_data(value)?;
+                data.telemetry = Some(telemetry);
+                self.apply_request_options(&mut data, &params).await?;
+                Ok(ConsensusResult::Reconciled { data: Box::new(data), report })
+            }
+            None => Ok(ConsensusResult::Unresolved(report)),
+        }
+    }
+
+    /// Fetch skip rate for a single validator
+    ///
+    /// Uses the `identity` filter of getBlockProduction so only this validator's
+    /// record is transferred. Returns `None` if the validator had no leader slots.
+    pub async fn fetch_validator_skip_rate(&self, validator_pubkey: &str) -> Result<Option<ValidatorSkipRate>> {
+        let params = BlockProductionRequest {
+            identity: Some(validator_pubkey.to_string()),
+            ..BlockProductionRequest::default()
+        };
+        self.fetch_identity_skip_rate(params).await
+    }
+
     /// Fetch skip rates for specific validators only
     pub async fn fetch_validator_skip_rates(
         &self,
         validator_pubkeys: Vec<String>,
     ) -> Result<Vec<ValidatorSkipRate>> {
-        // Since getBlockProduction doesn't support filtering by identity,
-        // we fetch all data and filter client-side
-        let data = self.fetch_block_production().await?;
-        
-        let validator_set: std::collections::HashSet<String> = validator_pubkeys.into_iter().collect();
-        let filtered_validators: Vec<ValidatorSkipRate> = data.validators
+        self.fetch_validator_skip_rates_with_params(validator_pubkeys, BlockProductionRequest::default())
+            .await
+    }
+
+    /// Fetch skip rates for specific validators with specific parameters
+    ///
+    /// Issues one identity-filtered request per validator, running up to
+    /// `max_concurrent_requests` of them at a time. Validators without leader
+    /// slots in the range are omitted from the result.
+    pub async fn fetch_validator_skip_rates_with_params(
+        &self,
+        validator_pubkeys: Vec<String>,
+        params: BlockProductionRequest,
+    ) -> Result<Vec<ValidatorSkipRate>> {
+        let mut seen = std::collections::HashSet::new();
+        let requests: Vec<BlockProductionRequest> = validator_pubkeys
             .into_iter()
-            .filter(|v| validator_set.contains(&v.pubkey))
+            .filter(|pubkey| seen.insert(pubkey.clone()))
+            .map(|pubkey| BlockProductionRequest {
+                identity: Some(pubkey),
+                ..params.clone()
+            })
             .collect();
-            
-        Ok(filtered_validators)
+
+        debug!(
+            validator_count = requests.len(),
+            max_concurrent = self.config.max_concurrent_requests,
+            "Fetching identity-filtered block production"
+        );
+
+        let mut validators: Vec<ValidatorSkipRate> = stream::iter(requests)
+            .map(|request| self.fetch_identity_skip_rate(request))
+            .buffered(self.config.max_concurrent_requests.max(1))
+            .try_filter_map(|validator| async move { Ok(validator) })
+            .try_collect()
+            .await?;
+
+        // Sort by skip rate (ascending - best performers first)
+        validators.sort_by(|a, b| a.skip_rate_percent.partial_cmp(&b.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(validators)
     }
 
     /// Fetch block production data for a specific slot range
+    ///
+    /// Ranges wider than `range_chunk_size` are split into chunks fetched concurrently
+    /// (up to `max_concurrent_requests`) and merged. If only some chunks fail, the
+    /// merged data is returned with the failures listed in `range_coverage`.
     pub async fn fetch_block_production_range(
         &self,
         first_slot: u64,
@@ -243,69 +948,351 @@ impl BlockProductionClient {
         let params = BlockProductionRequest {
             range: Some(SlotRange { first_slot, last_slot }),
             commitment: None,
+            identity: None,
+            include_skipped_slots: false,
+            include_stake: false,
+            include_validator_info: false,
+            include_version_breakdown: false,
         };
 
-        self.fetch_block_production_with_params(params).await
+        let chunk_size = self.config.range_chunk_size;
+        if chunk_size == 0 || last_slot - first_slot < chunk_size {
+            return self.fetch_block_production_with_params(params).await;
+        }
+
+        if let Some((data, _, _)) = self.cached_response(&params) {
+            return Ok(data);
+        }
+
+        let (data, answered_by) = self
+            .fetch_chunked_block_production(params.clone(), SlotRange { first_slot, last_slot }, chunk_size)
+            .await?;
+        // Only complete results are cached so failed chunks are retried on the next call
+        if data.range_coverage.as_ref().map_or(true, |coverage| !coverage.is_partial()) {
+            self.cache_response(params, &data, &answered_by);
+        }
+        Ok(data)
     }
 
-    /// Get validators with concerning skip rates (> 5%)
-    pub async fn get_concerning_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
-        let data = self.fetch_block_production().await?;
-        Ok(data.validators.into_iter()
-            .filter(super::types::ValidatorSkipRate::is_concerning)
-            .collect())
+    /// Get information about the current epoch
+    pub async fn current_epoch_info(&self) -> Result<EpochInfo> {
+        self.call_rpc("getEpochInfo", json!([])).await
     }
 
-    /// Get validators with perfect performance (0% skip rate)
-    pub async fn get_perfect_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
-        let data = self.fetch_block_production().await?;
-        Ok(data.validators.into_iter()
-            .filter(super::types::ValidatorSkipRate::is_perfect)
-            .collect())
+    /// Get the cluster epoch schedule
+    pub async fn fetch_epoch_schedule(&self) -> Result<EpochSchedule> {
+        self.call_rpc("getEpochSchedule", json!([])).await
     }
 
-    /// Get validators that are completely offline (100% skip rate)
-    pub async fn get_offline_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
-        let data = self.fetch_block_production().await?;
-        Ok(data.validators.into_iter()
-            .filter(super::types::ValidatorSkipRate::is_offline)
-            .collect())
+    /// Fetch block production data for the current epoch, tagged with epoch progress
+    pub async fn fetch_current_epoch_block_production(&self) -> Result<BlockProductionData> {
+        let epoch_info = self.current_epoch_info().await?;
+        self.fetch_epoch_block_production_with_info(epoch_info.epoch, &epoch_info).await
     }
 
-    /// Get significant validators (>= 50 slots) - these represent real network participants
-    pub async fn get_significant_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
-        let data = self.fetch_block_production().await?;
-        let mut validators: Vec<ValidatorSkipRate> = data.validators.into_iter()
-            .filter(super::types::ValidatorSkipRate::is_significant)
-            .collect();
-        
-        // Sort by skip rate (ascending) - better performers first
-        validators.sort_by(|a, b| a.skip_rate_percent.partial_cmp(&b.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
-        Ok(validators)
+    /// Fetch block production data for a specific epoch
+    ///
+    /// Epoch boundaries are resolved via getEpochInfo/getEpochSchedule. For the current
+    /// epoch the range ends at the current slot; completed epochs cover the full epoch.
+    pub async fn fetch_epoch_block_production(&self, epoch: u64) -> Result<BlockProductionData> {
+        let epoch_info = self.current_epoch_info().await?;
+        self.fetch_epoch_block_production_with_info(epoch, &epoch_info).await
+    }
+
+    /// Fetch block production for the `epochs` most recent epochs, including the current one
+    ///
+    /// Epochs are fetched concurrently up to `max_concurrent_requests`. Epochs the
+    /// node cannot serve (e.g. pruned ledger history) are reported in
+    /// `unavailable_epochs` instead of failing the whole call; any other error
+    /// (auth, rate limit, connection) is returned.
+    pub async fn fetch_epoch_history(&self, epochs: u64) -> Result<EpochHistory> {
+        if epochs == 0 {
+            return Err(BlockProductionError::config_error(
+                "Epoch history must cover at least one epoch",
+                Some("epochs"),
+                Some("Request one or more epochs"),
+            ));
+        }
+
+        let epoch_info = self.current_epoch_info().await?;
+        let schedule = self.fetch_epoch_schedule().await?;
+        let first_epoch = epoch_info.epoch.saturating_sub(epochs - 1);
+
+        info!(
+            first_epoch = first_epoch,
+            last_epoch = epoch_info.epoch,
+            "Fetching epoch history"
+        );
+
+        let results: Vec<(u64, Result<BlockProductionData>)> = stream::iter(first_epoch..=epoch_info.epoch)
+            .map(|epoch| {
+                let epoch_context = if epoch == epoch_info.epoch {
+                    epoch_info.epoch_context()
+                } else {
+                    let first_slot = schedule.first_slot_in_epoch(epoch);
+                    let last_slot = schedule.last_slot_in_epoch(epoch);
+                    EpochContext::new(epoch, first_slot, schedule.slots_in_epoch(epoch), last_slot)
+                };
+                async move {
+                    let result = self
+                        .fetch_epoch_context_block_production(epoch_context, epoch_info.absolute_slot)
+                        .await;
+                    (epoch, result)
+                }
+            })
+            .buffered(self.config.max_concurrent_requests.max(1))
+            .collect()
+            .await;
+
+        let mut fetched = Vec::new();
+        let mut unavailable_epochs = Vec::new();
+        for (epoch, result) in results {
+            match result {
+                Ok(data) => fetched.push(data),
+                Err(e) if Self::is_ledger_unavailable(&e) => {
+                    warn!(epoch = epoch, error = %e, "Epoch not available on this node");
+                    unavailable_epochs.push(UnavailableEpoch { epoch, error: e.to_string() });
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(EpochHistory::new(fetched, unavailable_epochs))
+    }
+
+    /// Project end-of-epoch skip rates for the current epoch
+    pub async fn project_current_epoch(&self) -> Result<EpochProjection> {
+        let data = self.fetch_current_epoch_block_production().await?;
+        self.project_epoch_end(&data).await
+    }
+
+    /// Project end-of-epoch skip rates from partial epoch data
+    ///
+    /// `data` must come from the epoch API so it carries an `epoch_context`. Each
+    /// validator's remaining leader slots are taken from getLeaderSchedule and
+    /// expected to be missed at its observed rate (blended with the cluster rate
+    /// for validators with few observed slots).
+    pub async fn project_epoch_end(&self, data: &BlockProductionData) -> Result<EpochProjection> {
+        let epoch_context = data.epoch_context.clone().ok_or_else(|| BlockProductionError::InvalidSlotRange {
+            message: "Projection requires epoch data (use fetch_current_epoch_block_production)".to_string(),
+            provided_range: Some((data.slot_range.first_slot, data.slot_range.last_slot)),
+            valid_range: None,
+        })?;
+
+        let observed_slot = data.slot_range.last_slot;
+        let remaining_leader_slots = if observed_slot < epoch_context.last_slot {
+            let remaining_range = SlotRange {
+                first_slot: observed_slot + 1,
+                last_slot: epoch_context.last_slot,
+            };
+            self.fetch_leader_slots(&remaining_range, None).await?
+        } else {
+            HashMap::new()
+        };
+
+        Ok(Self::calculate_epoch_projection(data, epoch_context, &remaining_leader_slots))
+    }
+
+    /// Fetch absolute leader slots per validator identity within a slot range
+    ///
+    /// Queries getLeaderSchedule once per epoch touched by the range and converts
+    /// the epoch-relative slot indices to absolute slots.
+    pub async fn fetch_leader_slots(
+        &self,
+        range: &SlotRange,
+        identity: Option<&str>,
+    ) -> Result<HashMap<String, Vec<u64>>> {
+        let schedule = self.fetch_epoch_schedule().await?;
+        let first_epoch = schedule.epoch_for_slot(range.first_slot);
+        let last_epoch = schedule.epoch_for_slot(range.last_slot);
+        let slots_in_range = range.first_slot..=range.last_slot;
+
+        let mut leader_slots: HashMap<String, Vec<u64>> = HashMap::new();
+
+        for epoch in first_epoch..=last_epoch {
+            let epoch_start = schedule.first_slot_in_epoch(epoch);
+            let params = match identity {
+                Some(identity) => json!([epoch_start, { "identity": identity }]),
+                None => json!([epoch_start]),
+            };
+
+            let epoch_schedule: Option<HashMap<String, Vec<u64>>> =
+                self.call_rpc("getLeaderSchedule", params).await?;
+            let epoch_schedule = epoch_schedule.ok_or_else(|| BlockProductionError::NoData {
+                requested_range: Some((range.first_slot, range.last_slot)),
+                reason: Some(format!("Leader schedule for epoch {epoch} is not available")),
+            })?;
+
+            for (pubkey, slot_indices) in epoch_schedule {
+                let slots = slot_indices
+                    .into_iter()
+                    .map(|index| epoch_start.saturating_add(index))
+                    .filter(|slot| slots_in_range.contains(slot));
+                leader_slots.entry(pubkey).or_default().extend(slots);
+            }
+        }
+
+        leader_slots.retain(|_, slots| !slots.is_empty());
+        for slots in leader_slots.values_mut() {
+            slots.sort_unstable();
+        }
+
+        Ok(leader_slots)
+    }
+
+    /// Fetch slots with a produced block within a slot range (inclusive)
+    ///
+    /// Splits the range into getBlocks-sized windows fetched concurrently.
+    /// getBlocks does not support `processed`, so it is queried at `confirmed`.
+    pub async fn fetch_confirmed_blocks(
+        &self,
+        range: &SlotRange,
+        commitment: Option<&str>,
+    ) -> Result<Vec<u64>> {
+        let commitment = commitment.map(|commitment| {
+            if commitment.eq_ignore_ascii_case("processed") { "confirmed" } else { commitment }
+        });
+        let mut windows = Vec::new();
+        let mut start = range.first_slot;
+        while start <= range.last_slot {
+            let end = start.saturating_add(MAX_GET_BLOCKS_RANGE - 1).min(range.last_slot);
+            windows.push((start, end));
+            if end == u64::MAX {
+                break;
+            }
+            start = end + 1;
+        }
+
+        let block_windows: Vec<Vec<u64>> = stream::iter(windows)
+            .map(|(start, end)| {
+                let params = match commitment {
+                    Some(commitment) => json!([start, end, { "commitment": commitment }]),
+                    None => json!([start, end]),
+                };
+                self.call_rpc::<Vec<u64>>("getBlocks", params)
+            })
+            .buffered(self.config.max_concurrent_requests.max(1))
+            .try_collect()
+            .await?;
+
+        Ok(block_windows.into_iter().flatten().collect())
+    }
+
+    /// Fetch vote accounts (stake, commission, delinquency) for all validators
+    pub async fn fetch_vote_accounts(&self) -> Result<VoteAccounts> {
+        self.call_rpc("getVoteAccounts", json!([])).await
+    }
+
+    /// Fetch gossip contact info (software version, feature set) for all cluster nodes
+    pub async fn fetch_cluster_nodes(&self) -> Result<Vec<ClusterNode>> {
+        self.call_rpc("getClusterNodes", json!([])).await
+    }
+
+    /// Fetch validator-info metadata for all validators, bypassing the cache
+    ///
+    /// Reads the Config program's validator-info accounts via getProgramAccounts
+    /// and returns them keyed by validator identity.
+    pub async fn fetch_validator_info(&self) -> Result<HashMap<String, ValidatorInfo>> {
+        let accounts: Vec<serde_json::Value> = self
+            .call_rpc("getProgramAccounts", json!([CONFIG_PROGRAM_ID, { "encoding": "jsonParsed" }]))
+            .await?;
+
+        let validator_info: HashMap<String, ValidatorInfo> = accounts
+            .iter()
+            .filter_map(ValidatorInfo::from_parsed_account)
+            .collect();
+
+        debug!(
+            config_accounts = accounts.len(),
+            validator_info_accounts = validator_info.len(),
+            "Decoded validator-info accounts"
+        );
+
+        Ok(validator_info)
+    }
+
+    /// Get validator-info metadata keyed by identity, cached for `validator_info_ttl`
+    pub async fn validator_info(&self) -> Result<Arc<HashMap<String, ValidatorInfo>>> {
+        let cached = self.validator_info_cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.config.validator_info_ttl)
+            .map(|(_, cached)| Arc::clone(cached));
+
+        if let Some(cached) = cached {
+            trace!("Using cached validator-info metadata");
+            return Ok(cached);
+        }
+
+        let validator_info = Arc::new(self.fetch_validator_info().await?);
+        *self.validator_info_cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner) =
+            Some((Instant::now(), Arc::clone(&validator_info)));
+        Ok(validator_info)
+    }
+
+    /// Drop cached validator-info metadata so the next lookup refetches it
+    pub fn invalidate_validator_info_cache(&self) {
+        *self.validator_info_cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner) = None;
+    }
+
+    /// Drop all cached block production responses
+    pub fn invalidate_response_cache(&self) {
+        self.lock_response_cache().clear();
+    }
+
+    /// Drop the cached response for one request, if any
+    pub fn invalidate_cached_response(&self, params: &BlockProductionRequest) {
+        self.lock_response_cache().remove(params);
+    }
+
+    /// Get validators with concerning skip rates (> 5%)
+    pub async fn get_concerning_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
+        let data = self.fetch_block_production().await?;
+        Ok(data.query().concerning().to_vec())
+    }
+
+    /// Get validators with perfect performance (0% skip rate)
+    pub async fn get_perfect_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
+        let data = self.fetch_block_production().await?;
+        Ok(data.query().perfect().to_vec())
+    }
+
+    /// Get validators that are completely offline (100% skip rate)
+    pub async fn get_offline_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
+        let data = self.fetch_block_production().await?;
+        Ok(data.query().offline().to_vec())
+    }
+
+    /// Get significant validators (>= 50 slots) - these represent real network participants
+    pub async fn get_significant_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
+        let data = self.fetch_block_production().await?;
+        // Sort by skip rate (ascending) - better performers first
+        Ok(data.query()
+            .significant()
+            .sort_by(ValidatorSortField::SkipRate, SortOrder::Ascending)
+            .to_vec())
     }
 
     /// Get validators with moderate skip rates (between 1% and 5%) - these are the interesting ones
     pub async fn get_moderate_performers(&self) -> Result<Vec<ValidatorSkipRate>> {
         let data = self.fetch_block_production().await?;
-        let mut validators: Vec<ValidatorSkipRate> = data.validators.into_iter()
-            .filter(|v| v.skip_rate_percent > 0.0 && v.skip_rate_percent <= 5.0)
-            .collect();
-        
         // Sort by skip rate (ascending) to show best moderate performers first
-        validators.sort_by(|a, b| a.skip_rate_percent.partial_cmp(&b.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
-        Ok(validators)
+        Ok(data.query()
+            .skip_rate_above(0.0)
+            .max_skip_rate(5.0)
+            .sort_by(ValidatorSortField::SkipRate, SortOrder::Ascending)
+            .to_vec())
     }
 
     /// Get high-activity validators (>1000 leader slots) sorted by skip rate - these are the important ones
     pub async fn get_high_activity_validators(&self) -> Result<Vec<ValidatorSkipRate>> {
         let data = self.fetch_block_production().await?;
-        let mut validators: Vec<ValidatorSkipRate> = data.validators.into_iter()
-            .filter(|v| v.leader_slots > 1000)
-            .collect();
-        
         // Sort by skip rate (ascending) - lower skip rate = better performance
-        validators.sort_by(|a, b| a.skip_rate_percent.partial_cmp(&b.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
-        Ok(validators)
+        Ok(data.query()
+            .high_activity()
+            .sort_by(ValidatorSortField::SkipRate, SortOrder::Ascending)
+            .to_vec())
     }
 
     /// Get validators in the worst percentile (95th percentile and above) - actionable for stake removal
@@ -313,23 +1300,477 @@ impl BlockProductionClient {
         let data = self.fetch_block_production().await?;
         let percentile_95 = data.statistics.skip_rate_95th_percentile;
         
-        let mut validators: Vec<ValidatorSkipRate> = data.validators.into_iter()
-            .filter(|v| v.skip_rate_percent >= percentile_95 && v.skip_rate_percent < 100.0) // Exclude completely offline
-            .collect();
-        
-        // Sort by skip rate (descending) - worst first
-        validators.sort_by(|a, b| b.skip_rate_percent.partial_cmp(&a.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
-        Ok(validators)
+        // Exclude completely offline, worst first
+        Ok(data.query()
+            .min_skip_rate(percentile_95)
+            .exclude_offline()
+            .sort_by(ValidatorSortField::SkipRate, SortOrder::Descending)
+            .to_vec())
     }
 
     // Internal methods
 
-    async fn fetch_raw_block_production(
+    async fn fetch_block_production_value(
         &self,
         params: &BlockProductionRequest,
-    ) -> Result<serde_json::Value> {
+        telemetry: &mut RequestTelemetry,
+    ) -> Result<(BlockProductionValue, &str)> {
         let request = Self::build_rpc_request(params);
-        self.make_rpc_request(request).await
+        let (body, answered_by) = self
+            .make_pooled_rpc_request(&request, self.block_production_mode(), telemetry)
+            .await?;
+        Ok((body.into_block_production_value()?, answered_by))
+    }
+
+    /// Fetch a slot range in concurrent chunks and merge the per-identity counts
+    ///
+    /// Also returns the endpoints that answered the chunks, comma-separated in
+    /// first-answer order.
+    #[allow(clippy::cast_precision_loss)]
+    async fn fetch_chunked_block_production(
+        &self,
+        params: BlockProductionRequest,
+        range: SlotRange,
+        chunk_size: u64,
+    ) -> Result<(BlockProductionData, String)> {
+        let chunks = range.chunks(chunk_size);
+        let chunk_count = chunks.len();
+
+        info!(
+            first_slot = range.first_slot,
+            last_slot = range.last_slot,
+            chunk_count = chunk_count,
+            "Fetching slot range in chunks"
+        );
+
+        let start = Instant::now();
+        let params_ref = &params;
+        let results: Vec<_> = stream::iter(chunks)
+            .map(|chunk| async move {
+                let chunk_params = BlockProductionRequest {
+                    range: Some(chunk.clone()),
+                    ..params_ref.clone()
+                };
+                let mut telemetry = RequestTelemetry::new("getBlockProduction");
+                let result = self.fetch_block_production_value(&chunk_params, &mut telemetry).await;
+                (chunk, result, telemetry)
+            })
+            .buffered(self.config.max_concurrent_requests.max(1))
+            .collect()
+            .await;
+
+        let mut telemetry = RequestTelemetry::new("getBlockProduction");
+
+        let mut by_identity: HashMap<String, (u64, u64)> = HashMap::new();
+        let mut failed_chunks = Vec::new();
+        let mut first_error = None;
+        let mut covered_slots = 0;
+        let mut answered_by: Vec<&str> = Vec::new();
+
+        for (chunk, result, chunk_telemetry) in results {
+            telemetry.merge(chunk_telemetry);
+            match result {
+                Ok((value, endpoint)) => {
+                    if !answered_by.contains(&endpoint) {
+                        answered_by.push(endpoint);
+                    }
+                    covered_slots += chunk.last_slot - chunk.first_slot + 1;
+                    for (pubkey, (leader_slots, blocks_produced)) in value.by_identity {
+                        let entry = by_identity.entry(pubkey).or_insert((0, 0));
+                        entry.0 += leader_slots;
+                        entry.1 += blocks_produced;
+                    }
+                }
+                Err(e) => {
+                    warn!(
+                        first_slot = chunk.first_slot,
+                        last_slot = chunk.last_slot,
+                        error = %e,
+                        "Range chunk failed"
+                    );
+                    failed_chunks.push(FailedRangeChunk { range: chunk, error: e.to_string() });
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        if failed_chunks.len() == chunk_count {
+            if let Some(e) = first_error {
+                return Err(e);
+            }
+        }
+
+        let requested_slots = range.last_slot - range.first_slot + 1;
+        let range_coverage = RangeCoverage {
+            requested_range: range.clone(),
+            chunk_count,
+            failed_chunks,
+            covered_slots,
+            coverage_percent: (covered_slots as f64 / requested_slots as f64) * 100.0,
+        };
+
+        telemetry.total_duration_ms = duration_ms(start.elapsed());
+
+        let mut data = Self::build_block_production_data(BlockProductionValue { by_identity, range })?;
+        data.range_coverage = Some(range_coverage);
+        data.telemetry = Some(telemetry);
+        self.apply_request_options(&mut data, &params).await?;
+        Ok((data, answered_by.join(", ")))
+    }
+
+    /// Compare provider responses and build majority-agreed counts where possible
+    fn reconcile_provider_responses(
+        requested_range: SlotRange,
+        responses: Vec<(String, Result<BlockProductionValue>)>,
+    ) -> (Option<BlockProductionValue>, ConsensusReport) {
+        let mut providers = Vec::new();
+        let mut answered: Vec<(String, BlockProductionValue)> = Vec::new();
+
+        for (endpoint, result) in responses {
+            match result {
+                Ok(value) => {
+                    providers.push(ProviderResponse {
+                        endpoint: endpoint.clone(),
+                        range: Some(value.range.clone()),
+                        validator_count: value.by_identity.len(),
+                        total_leader_slots: value.by_identity.values().map(|(leader_slots, _)| leader_slots).sum(),
+                        total_blocks_produced: value.by_identity.values().map(|(_, produced)| produced).sum(),
+                        error: None,
+                    });
+                    answered.push((endpoint, value));
+                }
+                Err(e) => providers.push(ProviderResponse {
+                    endpoint,
+                    range: None,
+                    validator_count: 0,
+                    total_leader_slots: 0,
+                    total_blocks_produced: 0,
+                    error: Some(e.to_string()),
+                }),
+            }
+        }
+
+        // A strict majority of at least two answering providers is required
+        let responding = answered.len();
+        let majority = |votes: usize| responding >= 2 && votes * 2 > responding;
+
+        let mut range_votes: HashMap<&SlotRange, usize> = HashMap::new();
+        for (_, value) in &answered {
+            *range_votes.entry(&value.range).or_default() += 1;
+        }
+        let ranges_match = range_votes.len() <= 1;
+        let agreed_range = range_votes
+            .iter()
+            .find(|(_, &votes)| majority(votes))
+            .map(|(range, _)| (*range).clone());
+
+        let mut pubkeys: Vec<&String> = answered.iter()
+            .flat_map(|(_, value)| value.by_identity.keys())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        pubkeys.sort();
+
+        let mut by_identity = HashMap::new();
+        let mut agreeing_validators = 0;
+        let mut disagreements = Vec::new();
+
+        for pubkey in pubkeys {
+            let counts: Vec<ProviderCounts> = answered.iter()
+                .map(|(endpoint, value)| ProviderCounts {
+                    endpoint: endpoint.clone(),
+                    counts: value.by_identity.get(pubkey).copied(),
+                })
+                .collect();
+
+            let mut votes: HashMap<Option<(u64, u64)>, usize> = HashMap::new();
+            for provider in &counts {
+                *votes.entry(provider.counts).or_default() += 1;
+            }
+            let agreed = votes.iter().find(|(_, &n)| majority(n)).map(|(counts, _)| *counts);
+
+            if let Some(Some(agreed_counts)) = agreed {
+                by_identity.insert(pubkey.clone(), agreed_counts);
+            }
+
+            if votes.len() == 1 {
+                agreeing_validators += 1;
+            } else {
+                disagreements.push(ValidatorDisagreement {
+                    pubkey: pubkey.clone(),
+                    counts,
+                    reconciled: agreed.is_some(),
+                });
+            }
+        }
+
+        let all_reconciled = disagreements.iter().all(|d| d.reconciled);
+        let reconciled = match agreed_range {
+            Some(range) if all_reconciled => Some(BlockProductionValue { by_identity, range }),
+            _ => None,
+        };
+
+        let report = ConsensusReport {
+            requested_range,
+            providers,
+            ranges_match,
+            agreeing_validators,
+            disagreements,
+        };
+
+        (reconciled, report)
+    }
+
+    #[instrument(skip(self, epoch_info), fields(current_epoch = epoch_info.epoch))]
+    async fn fetch_epoch_block_production_with_info(
+        &self,
+        epoch: u64,
+        epoch_info: &EpochInfo,
+    ) -> Result<BlockProductionData> {
+        if epoch > epoch_info.epoch {
+            return Err(BlockProductionError::InvalidSlotRange {
+                message: format!(
+                    "Epoch {epoch} has not started yet (current epoch is {})",
+                    epoch_info.epoch
+                ),
+                provided_range: None,
+                valid_range: None,
+            });
+        }
+
+        let epoch_context = if epoch == epoch_info.epoch {
+            epoch_info.epoch_context()
+        } else {
+            let schedule = self.fetch_epoch_schedule().await?;
+            let first_slot = schedule.first_slot_in_epoch(epoch);
+            let last_slot = schedule.last_slot_in_epoch(epoch);
+            EpochContext::new(epoch, first_slot, schedule.slots_in_epoch(epoch), last_slot)
+        };
+
+        self.fetch_epoch_context_block_production(epoch_context, epoch_info.absolute_slot).await
+    }
+
+    /// Fetch block production for a resolved epoch, capped at the current slot
+    async fn fetch_epoch_context_block_production(
+        &self,
+        epoch_context: EpochContext,
+        current_slot: u64,
+    ) -> Result<BlockProductionData> {
+        // getBlockProduction rejects ranges past the current slot
+        let last_slot = epoch_context.last_slot.min(current_slot);
+
+        debug!(
+            epoch = epoch_context.epoch,
+            first_slot = epoch_context.first_slot,
+            last_slot = last_slot,
+            progress_percent = epoch_context.progress_percent,
+            "Resolved epoch slot range"
+        );
+
+        let params = BlockProductionRequest {
+            range: Some(SlotRange { first_slot: epoch_context.first_slot, last_slot }),
+            commitment: None,
+            identity: None,
+            include_skipped_slots: false,
+            include_stake: false,
+            include_validator_info: false,
+            include_version_breakdown: false,
+        };
+
+        let mut data = self.fetch_block_production_with_params(params).await?;
+        data.epoch_context = Some(epoch_context);
+        Ok(data)
+    }
+
+    fn lock_response_cache(&self) -> std::sync::MutexGuard<'_, HashMap<BlockProductionRequest, CachedResponse>> {
+        self.response_cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Look up a fresh cached response: (data, endpoint that answered, age)
+    fn cached_response(&self, params: &BlockProductionRequest) -> Option<(BlockProductionData, String, Duration)> {
+        let ttl = self.config.response_cache_ttl;
+        if ttl.is_zero() {
+            return None;
+        }
+
+        let cache = self.lock_response_cache();
+        let cached = cache.get(params).filter(|cached| cached.fetched_at.elapsed() < ttl)?;
+        let age = cached.fetched_at.elapsed();
+        trace!(cache_age_ms = age.as_millis(), "Using cached block production response");
+        Some((cached.data.clone(), cached.answered_by.clone(), age))
+    }
+
+    fn cache_response(&self, params: BlockProductionRequest, data: &BlockProductionData, answered_by: &str) {
+        let ttl = self.config.response_cache_ttl;
+        if ttl.is_zero() {
+            return;
+        }
+
+        let mut cache = self.lock_response_cache();
+        cache.retain(|_, cached| cached.fetched_at.elapsed() < ttl);
+        cache.insert(params, CachedResponse {
+            fetched_at: Instant::now(),
+            // Cache hits make no RPC call, so they carry no telemetry
+            data: BlockProductionData { telemetry: None, ..data.clone() },
+            answered_by: answered_by.to_string(),
+        });
+    }
+
+    /// Call a parameterless RPC method on the primary endpoint, recording its latency and any error
+    async fn diagnostic_call<T: DeserializeOwned>(
+        &self,
+        calls: &mut Vec<RpcCallDiagnostic>,
+        method: &str,
+    ) -> Option<T> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": []
+        });
+
+        let start = Instant::now();
+        let result: Result<T> = self
+            .make_primary_rpc_request(&request)
+            .await
+            .and_then(|response| response.result(method));
+        let latency_ms = duration_ms(start.elapsed());
+
+        debug!(method = method, latency_ms = latency_ms, success = result.is_ok(), "Diagnostic call finished");
+
+        let (value, error) = match result {
+            Ok(value) => (Some(value), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        calls.push(RpcCallDiagnostic { method: method.to_string(), latency_ms, error });
+        value
+    }
+
+    /// Call an arbitrary RPC method and deserialize its `result` field
+    async fn call_rpc<T: DeserializeOwned>(&self, method: &str, params: serde_json::Value) -> Result<T> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params
+        });
+
+        self.make_rpc_request(request).await?.result(method)
+    }
+
+    async fn fetch_identity_skip_rate(
+        &self,
+        params: BlockProductionRequest,
+    ) -> Result<Option<ValidatorSkipRate>> {
+        let Some(identity) = params.identity.clone() else {
+            return Err(BlockProductionError::Internal {
+                message: "Identity lookup requires an identity filter".to_string(),
+                location: Some("fetch_identity_skip_rate".to_string()),
+                debug_context: None,
+            });
+        };
+
+        let (mut value, _) = self.fetch_block_production_value(&params, &mut RequestTelemetry::default()).await?;
+        let slot_range = value.range.clone();
+
+        let Some((leader_slots, blocks_produced)) = value.by_identity.remove(&identity) else {
+            return Ok(None);
+        };
+
+        let mut validators = vec![ValidatorSkipRate::new(identity, leader_slots, blocks_produced)];
+        if params.include_skipped_slots {
+            self.attach_skipped_slots(&mut validators, &slot_range, &params).await?;
+        }
+        if params.include_stake {
+            self.attach_stake_info(&mut validators).await?;
+        }
+        if params.include_validator_info {
+            let validator_info = self.validator_info().await?;
+            for validator in &mut validators {
+                validator.validator_info = validator_info.get(&validator.pubkey).cloned();
+            }
+        }
+        Ok(validators.pop())
+    }
+
+    /// Apply the opt-in enrichments requested in `params` to processed data
+    async fn apply_request_options(
+        &self,
+        data: &mut BlockProductionData,
+        params: &BlockProductionRequest,
+    ) -> Result<()> {
+        if params.include_skipped_slots {
+            self.attach_skipped_slots(&mut data.validators, &data.slot_range, params).await?;
+        }
+
+        if params.include_stake {
+            self.attach_stake_info(&mut data.validators).await?;
+            data.statistics.stake_weighted = Self::calculate_stake_weighted_statistics(&data.validators);
+        }
+
+        if params.include_validator_info {
+            let validator_info = self.validator_info().await?;
+            data.apply_validator_info(&validator_info);
+        }
+
+        if params.include_version_breakdown {
+            let nodes = self.fetch_cluster_nodes().await?;
+            data.version_statistics = Some(Self::calculate_version_statistics(&data.validators, &nodes));
+        }
+
+        Ok(())
+    }
+
+    /// Join getVoteAccounts data onto validators by identity
+    async fn attach_stake_info(&self, validators: &mut [ValidatorSkipRate]) -> Result<()> {
+        let mut stake_by_identity = self.fetch_vote_accounts().await?.stake_by_identity();
+
+        debug!(
+            vote_accounts = stake_by_identity.len(),
+            validators = validators.len(),
+            "Joining vote account stake onto validators"
+        );
+
+        for validator in validators.iter_mut() {
+            validator.stake_info = stake_by_identity.remove(&validator.pubkey);
+        }
+
+        Ok(())
+    }
+
+    /// Resolve exact skipped slots and attach them to the matching validators
+    async fn attach_skipped_slots(
+        &self,
+        validators: &mut [ValidatorSkipRate],
+        slot_range: &SlotRange,
+        params: &BlockProductionRequest,
+    ) -> Result<()> {
+        let leader_slots = self.fetch_leader_slots(slot_range, params.identity.as_deref()).await?;
+        let produced: HashSet<u64> = self
+            .fetch_confirmed_blocks(slot_range, params.commitment.as_deref())
+            .await?
+            .into_iter()
+            .collect();
+
+        debug!(
+            first_slot = slot_range.first_slot,
+            last_slot = slot_range.last_slot,
+            leaders = leader_slots.len(),
+            produced_blocks = produced.len(),
+            "Resolved leader schedule and produced blocks"
+        );
+
+        for validator in validators.iter_mut() {
+            if let Some(slots) = leader_slots.get(&validator.pubkey) {
+                validator.skipped_slots = slots
+                    .iter()
+                    .copied()
+                    .filter(|slot| !produced.contains(slot))
+                    .collect();
+            }
+        }
+
+        Ok(())
     }
 
     fn build_rpc_request(params: &BlockProductionRequest) -> serde_json::Value {
@@ -349,6 +1790,10 @@ impl BlockProductionClient {
             rpc_params.insert("commitment".to_string(), json!(commitment));
         }
 
+        if let Some(identity) = &params.identity {
+            rpc_params.insert("identity".to_string(), json!(identity));
+        }
+
         json!({
             "jsonrpc": "2.0",
             "id": 1,
@@ -357,291 +1802,660 @@ impl BlockProductionClient {
         })
     }
 
-    #[instrument(skip(self, request), fields(endpoint = %self.config.rpc_endpoint))]
-    async fn make_rpc_request(&self, request: serde_json::Value) -> Result<serde_json::Value> {
+    /// Response mode for getBlockProduction calls
+    const fn block_production_mode(&self) -> ResponseMode {
+        if self.config.streaming_decode {
+            ResponseMode::StreamBlockProduction
+        } else {
+            ResponseMode::Buffered
+        }
+    }
+
+    async fn make_rpc_request(&self, request: serde_json::Value) -> Result<RpcBody> {
+        let mut telemetry = RequestTelemetry::default();
+        self.make_pooled_rpc_request(&request, ResponseMode::Buffered, &mut telemetry)
+            .await
+            .map(|(response, _)| response)
+    }
+
+    /// Send a request to the primary endpoint only, without failing over
+    async fn make_primary_rpc_request(&self, request: &serde_json::Value) -> Result<RpcBody> {
+        let mut telemetry = RequestTelemetry::default();
+        self.call_endpoint(0, request, ResponseMode::Buffered, &mut telemetry).await
+    }
+
+    /// Send a request through the endpoint pool, failing over on retryable errors
+    ///
+    /// Healthy endpoints are tried in configured order (primary first); endpoints
+    /// with repeated recent failures are tried last. Returns the response together
+    /// with the URL of the endpoint that answered; every attempt is recorded in
+    /// `telemetry`, including attempts of failed requests.
+    async fn make_pooled_rpc_request(
+        &self,
+        request: &serde_json::Value,
+        mode: ResponseMode,
+        telemetry: &mut RequestTelemetry,
+    ) -> Result<(RpcBody, &str)> {
+        let start = Instant::now();
+        let result = self.try_endpoints(request, mode, telemetry).await;
+        telemetry.total_duration_ms = duration_ms(start.elapsed());
+        result
+    }
+
+    async fn try_endpoints(
+        &self,
+        request: &serde_json::Value,
+        mode: ResponseMode,
+        telemetry: &mut RequestTelemetry,
+    ) -> Result<(RpcBody, &str)> {
+        let order = self.endpoint_order();
+        let mut last_error = None;
+
+        for (position, &index) in order.iter().enumerate() {
+            let endpoint = &self.endpoints[index];
+
+            match self.call_endpoint(index, request, mode, telemetry).await {
+                Ok(response) => return Ok((response, endpoint.label.as_str())),
+                Err(e) if Self::is_endpoint_failure(&e) => {
+                    if let Some(&next) = order.get(position + 1) {
+                        warn!(
+                            endpoint = %endpoint.label,
+                            next_endpoint = %self.endpoints[next].label,
+                            error = %e,
+                            "Endpoint failed, failing over"
+                        );
+                    }
+                    last_error = Some(e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| BlockProductionError::Internal {
+            message: "No RPC endpoints configured".to_string(),
+            location: Some("try_endpoints".to_string()),
+            debug_context: None,
+        }))
+    }
+
+    /// Send a request to one endpoint through its circuit breaker and record the outcome
+    ///
+    /// Errors the endpoint answered with (auth, RPC, parsing) count as successes for
+    /// its health; only failures to get an answer count against it.
+    async fn call_endpoint(
+        &self,
+        index: usize,
+        request: &serde_json::Value,
+        mode: ResponseMode,
+        telemetry: &mut RequestTelemetry,
+    ) -> Result<RpcBody> {
+        let endpoint = &self.endpoints[index];
+        let breaker = self.config.circuit_breaker.as_ref();
+        if let Some(breaker) = breaker {
+            endpoint.acquire_circuit(breaker)?;
+        }
+
+        let method = request.get("method").and_then(|v| v.as_str()).unwrap_or("unknown");
+        let rate_limiters = self.endpoint_rate_limiters(index, method);
+
+        let start = Instant::now();
+        let result = self
+            .make_endpoint_request(endpoint, &rate_limiters, request, mode, telemetry)
+            .await;
+        match &result {
+            Err(e) if Self::is_endpoint_failure(e) => endpoint.record_failure(breaker),
+            _ => endpoint.record_success(start.elapsed()),
+        }
+        result
+    }
+
+    /// Whether an error means the node does not have the requested ledger data
+    ///
+    /// Covers empty results, the RPC codes for cleaned-up, unavailable and
+    /// skipped slots (-32001, -32004, -32007, -32009) and getBlockProduction's
+    /// -32602 "firstSlot ... is too small" for ranges older than the SlotHistory
+    /// window. Retried requests are judged by their last error.
+    fn is_ledger_unavailable(error: &BlockProductionError) -> bool {
+        match error {
+            BlockProductionError::NoData { .. } => true,
+            BlockProductionError::Rpc { code: -32602, message, .. } => message.contains("is too small"),
+            BlockProductionError::Rpc { code, .. } => matches!(code, -32001 | -32004 | -32007 | -32009),
+            BlockProductionError::RetryExhausted { last_error, .. } => Self::is_ledger_unavailable(last_error),
+            _ => false,
+        }
+    }
+
+    /// Whether an error means the endpoint could not answer (and another one should be tried)
+    fn is_endpoint_failure(error: &BlockProductionError) -> bool {
+        error.is_retryable() || matches!(error, BlockProductionError::RetryExhausted { .. })
+    }
+
+    /// Endpoint indices in the order they should be tried
+    ///
+    /// Healthy endpoints come first. Within each group endpoints are ranked by
+    /// tracked success rate, then by average latency, then by pool position.
+    fn endpoint_order(&self) -> Vec<usize> {
+        let states: Vec<(bool, f64, Option<f64>)> = self.endpoints.iter()
+            .map(|endpoint| {
+                let health = endpoint.health();
+                (health.is_healthy(), health.health_score(), health.average_latency_ms)
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..self.endpoints.len()).collect();
+        order.sort_by(|&a, &b| {
+            let (healthy_a, score_a, latency_a) = states[a];
+            let (healthy_b, score_b, latency_b) = states[b];
+            healthy_b.cmp(&healthy_a)
+                .then(score_b.partial_cmp(&score_a).unwrap_or(std::cmp::Ordering::Equal))
+                .then(match (latency_a, latency_b) {
+                    (Some(latency_a), Some(latency_b)) => latency_a
+                        .partial_cmp(&latency_b)
+                        .unwrap_or(std::cmp::Ordering::Equal),
+                    _ => std::cmp::Ordering::Equal,
+                })
+                .then(a.cmp(&b))
+        });
+        order
+    }
+
+    /// Endpoint-wide and per-method limiters that apply to a request
+    fn endpoint_rate_limiters(&self, index: usize, method: &str) -> Vec<&SharedRateLimiter> {
+        let (rate_limiter, method_rate_limits) = match index {
+            0 => (self.config.rate_limiter.as_ref(), &self.config.method_rate_limits),
+            _ => match self.config.fallback_endpoints.get(index - 1) {
+                Some(fallback) => (fallback.rate_limiter.as_ref(), &fallback.method_rate_limits),
+                None => return Vec::new(),
+            },
+        };
+        rate_limiter.into_iter().chain(method_rate_limits.get(method)).collect()
+    }
+
+    #[instrument(skip(self, endpoint, rate_limiters, request, telemetry), fields(endpoint = %endpoint.label))]
+    async fn make_endpoint_request(
+        &self,
+        endpoint: &RpcEndpoint,
+        rate_limiters: &[&SharedRateLimiter],
+        request: &serde_json::Value,
+        mode: ResponseMode,
+        telemetry: &mut RequestTelemetry,
+    ) -> Result<RpcBody> {
         let request_id = request.get("id").and_then(serde_json::Value::as_u64).unwrap_or(0);
         let method = request.get("method").and_then(|v| v.as_str()).unwrap_or("unknown");
+        telemetry.method = method.to_string();
         
         info!(
             request_id = request_id,
             method = method,
-            endpoint = %self.config.rpc_endpoint,
+            endpoint = %endpoint.label,
             "Starting RPC request"
         );
         
-        // Apply rate limiting if configured
-        if let Some(rate_limiter) = &self.config.rate_limiter {
-            debug!("Checking rate limiter");
-            let start_wait = Instant::now();
-            rate_limiter.until_ready().await;
-            let wait_duration = start_wait.elapsed();
-            
-            if wait_duration > Duration::from_millis(10) {
-                debug!(
-                    wait_duration_ms = wait_duration.as_millis(),
-                    "Rate limiter delayed request"
-                );
-            }
-        } else {
-            trace!("No rate limiting configured");
-        }
-
         let mut error_history = Vec::new();
         let total_start = Instant::now();
-        let max_attempts = self.config.retry_attempts;
+        let policy = &self.config.retry_policy;
+        // At least one attempt is always made
+        let max_attempts = policy.max_attempts.unwrap_or(self.config.retry_attempts).max(1);
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            // Retries are requests too: every attempt goes through the limiters
+            Self::wait_for_rate_limiters(rate_limiters, telemetry).await;
 
-        for attempt in 1..=max_attempts {
             let attempt_start = Instant::now();
-            
-            debug!(
-                attempt = attempt,
-                max_attempts = max_attempts,
-                timeout_ms = self.config.timeout.as_millis(),
-                "Attempting RPC request"
-            );
+            let mut record = RpcAttempt {
+                endpoint: endpoint.label.clone(),
+                attempt,
+                duration_ms: 0,
+                http_status: None,
+                response_bytes: None,
+                error: None,
+                backoff_ms: None,
+            };
 
-            // Make the HTTP request with timeout
-            let http_result = timeout(
-                self.config.timeout,
-                self.http_client
-                    .post(&self.config.rpc_endpoint)
-                    .json(&request)
-                    .send(),
-            ).await;
-
-            let response = match http_result {
-                Ok(Ok(response)) => {
-                    debug!(
-                        status = response.status().as_u16(),
-                        attempt_duration_ms = attempt_start.elapsed().as_millis(),
-                        "HTTP request completed"
-                    );
-                    response
-                },
-                Ok(Err(e)) => {
-                    let error_msg = format!("HTTP error on attempt {attempt}: {e}");
-                    error_history.push(error_msg.clone());
-                    
-                    warn!(
+            let result = self
+                .send_rpc_attempt(endpoint, rate_limiters, request, mode, &mut error_history, &mut record)
+                .await;
+            record.duration_ms = duration_ms(attempt_start.elapsed());
+            telemetry.http_status = record.http_status;
+
+            let error = match result {
+                Ok(body) => {
+                    telemetry.response_bytes = record.response_bytes;
+                    telemetry.context_slot = body.context_slot();
+                    telemetry.attempts.push(record);
+                    info!(
+                        request_id = request_id,
+                        method = method,
                         attempt = attempt,
-                        error = %e,
-                        attempt_duration_ms = attempt_start.elapsed().as_millis(),
-                        "HTTP request failed"
+                        total_duration_ms = total_start.elapsed().as_millis(),
+                        "RPC request completed successfully"
                     );
-
-                    // Determine if this is a retryable error
-                    if e.is_timeout() {
-                        if attempt == max_attempts {
-                            return Err(BlockProductionError::Timeout {
-                                duration: self.config.timeout,
-                                operation: format!("RPC {method} request"),
-                                timeout_type: TimeoutType::Request,
-                            });
-                        }
-                        continue;
-                    } else if e.is_connect() {
-                        if attempt == max_attempts {
-                            return Err(BlockProductionError::ConnectionFailed {
-                                endpoint: self.config.rpc_endpoint.clone(),
-                                source: Box::new(e),
-                                endpoint_reachable: None,
-                            });
-                        }
-                        continue;
-                    }
-                    return Err(BlockProductionError::Http {
-                        source: e,
-                        context: Some(format!("RPC {method} request attempt {attempt}")),
-                    });
+                    return Ok(body);
                 },
-                Err(_) => {
-                    let error_msg = format!("Request timeout on attempt {} after {:?}", attempt, self.config.timeout);
-                    error_history.push(error_msg.clone());
-                    
-                    warn!(
-                        attempt = attempt,
-                        timeout_ms = self.config.timeout.as_millis(),
-                        "Request timed out"
-                    );
+                Err(e) => e,
+            };
+            record.error = Some(error.to_string());
 
-                    if attempt == max_attempts {
-                        return Err(BlockProductionError::Timeout {
-                            duration: self.config.timeout,
-                            operation: format!("RPC {method} request"),
-                            timeout_type: TimeoutType::Request,
-                        });
-                    }
-                    continue;
+            if !policy.should_retry(&error) {
+                telemetry.attempts.push(record);
+                return Err(error);
+            }
+
+            let attempts_allowed = policy.max_attempts_for(&error, max_attempts);
+            let delay = policy.backoff_delay(&error, attempt);
+            let out_of_attempts = attempt >= attempts_allowed;
+            if out_of_attempts || !policy.within_budget(total_start.elapsed(), delay) {
+                telemetry.attempts.push(record);
+
+                // A single configured attempt is not a retry: surface the error itself
+                if attempt == 1 && out_of_attempts {
+                    return Err(error);
                 }
-            };
 
-            // Check HTTP status
-            let status = response.status();
-            if !status.is_success() {
-                let error_msg = format!("HTTP {} error on attempt {}", status.as_u16(), attempt);
-                error_history.push(error_msg.clone());
-                
+                error!(
+                    request_id = request_id,
+                    method = method,
+                    total_attempts = attempt,
+                    total_duration_ms = total_start.elapsed().as_millis(),
+                    budget_exhausted = !out_of_attempts,
+                    "All retry attempts exhausted"
+                );
+                return Err(BlockProductionError::RetryExhausted {
+                    attempts: attempt,
+                    total_duration: total_start.elapsed(),
+                    last_error: Box::new(error),
+                    error_history,
+                });
+            }
+
+            debug!(
+                attempt = attempt,
+                delay_ms = delay.as_millis(),
+                category = ?error.category(),
+                "Retrying RPC request after backoff"
+            );
+            record.backoff_ms = Some(duration_ms(delay));
+            telemetry.attempts.push(record);
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Wait on every limiter that applies to a request and record the wait
+    async fn wait_for_rate_limiters(rate_limiters: &[&SharedRateLimiter], telemetry: &mut RequestTelemetry) {
+        if rate_limiters.is_empty() {
+            trace!("No rate limiting configured");
+            return;
+        }
+
+        debug!(limiters = rate_limiters.len(), "Checking rate limiters");
+        let start_wait = Instant::now();
+        for rate_limiter in rate_limiters {
+            rate_limiter.until_ready().await;
+        }
+        let wait_duration = start_wait.elapsed();
+        telemetry.rate_limit_wait_ms += duration_ms(wait_duration);
+        telemetry.effective_rate = rate_limiters.iter()
+            .map(|rate_limiter| rate_limiter.effective_rate())
+            .reduce(f64::min);
+
+        if wait_duration >= Duration::from_millis(1) {
+            telemetry.rate_limited = true;
+        }
+        if wait_duration > Duration::from_millis(10) {
+            debug!(
+                wait_duration_ms = wait_duration.as_millis(),
+                "Rate limiter delayed request"
+            );
+        }
+    }
+
+    /// `reqwest::Error` for an HTTP error status, so every transport surfaces the same `Http` error
+    fn status_error(url: &str, status: reqwest::StatusCode) -> Option<reqwest::Error> {
+        let mut response = http::Response::new(Vec::new());
+        *response.status_mut() = status;
+        let error = reqwest::Response::from(response).error_for_status().err()?;
+        Some(match reqwest::Url::parse(url) {
+            Ok(url) => error.with_url(url),
+            Err(_) => error.without_url(),
+        })
+    }
+
+    /// Send a single attempt of an RPC request, mapping every failure to a typed error
+    async fn send_rpc_attempt(
+        &self,
+        endpoint: &RpcEndpoint,
+        rate_limiters: &[&SharedRateLimiter],
+        request: &serde_json::Value,
+        mode: ResponseMode,
+        error_history: &mut Vec<String>,
+        record: &mut RpcAttempt,
+    ) -> Result<RpcBody> {
+        let attempt_start = Instant::now();
+        let attempt = record.attempt;
+        let method = request.get("method").and_then(|v| v.as_str()).unwrap_or("unknown");
+
+        debug!(
+            attempt = attempt,
+            timeout_ms = self.config.timeout.as_millis(),
+            "Attempting RPC request"
+        );
+
+        let transport_request = TransportRequest {
+            endpoint: endpoint.url.expose(),
+            headers: &endpoint.headers,
+            body: request,
+            timeout: self.config.timeout,
+            max_response_bytes: self.config.max_response_size,
+        };
+
+        let send = async {
+            match mode {
+                ResponseMode::Buffered => self.transport.send(transport_request).await
+                    .map(|response| (response.status, response.headers, ResponseBody::Buffered(response.body))),
+                ResponseMode::StreamBlockProduction => self.transport.send_streaming(transport_request).await
+                    .map(|response| (response.status, response.headers, ResponseBody::Streaming(response.body))),
+            }
+        };
+
+        // Send through the transport with timeout
+        let (status_code, headers, response_body) = match timeout(self.config.timeout, send).await {
+            Ok(Ok(response)) => {
+                debug!(
+                    status = response.0,
+                    attempt_duration_ms = attempt_start.elapsed().as_millis(),
+                    "HTTP request completed"
+                );
+                response
+            },
+            Ok(Err(e)) => {
+                error_history.push(format!("HTTP error on attempt {attempt}: {e}"));
+
+                warn!(
+                    attempt = attempt,
+                    error = %e,
+                    attempt_duration_ms = attempt_start.elapsed().as_millis(),
+                    "HTTP request failed"
+                );
+                return Err(e);
+            },
+            Err(_) => {
+                error_history.push(format!("Request timeout on attempt {} after {:?}", attempt, self.config.timeout));
+
                 warn!(
                     attempt = attempt,
-                    status = status.as_u16(),
-                    "HTTP request returned error status"
+                    timeout_ms = self.config.timeout.as_millis(),
+                    "Request timed out"
                 );
 
-                // Handle specific HTTP status codes
-                match status.as_u16() {
-                    429 => {
-                        let retry_after = response
-                            .headers()
-                            .get("retry-after")
-                            .and_then(|v| v.to_str().ok())
-                            .and_then(|s| s.parse::<u64>().ok())
-                            .map(Duration::from_secs);
-                        
-                        debug!(
-                            retry_after_secs = retry_after.as_ref().map(std::time::Duration::as_secs),
-                            "Rate limit exceeded, will retry"
-                        );
+                return Err(BlockProductionError::Timeout {
+                    duration: self.config.timeout,
+                    operation: format!("RPC {method} request"),
+                    timeout_type: TimeoutType::Request,
+                });
+            }
+        };
 
-                        if attempt == max_attempts {
-                            return Err(BlockProductionError::RateLimit {
-                                requests: 0, // Unknown from this context
-                                window: Duration::from_secs(60), // Default window
-                                limit: 0, // Unknown from this context  
-                                retry_after,
-                            });
-                        }
-                        
-                        // Wait for retry delay
-                        if let Some(delay) = retry_after {
-                            tokio::time::sleep(delay).await;
-                        } else {
-                            tokio::time::sleep(Duration::from_millis(100 * u64::from(attempt))).await;
-                        }
-                        continue;
-                    },
-                    401 | 403 => {
-                        error!(status = status.as_u16(), "Authentication failed");
-                        return Err(BlockProductionError::Auth {
-                            message: format!("HTTP {}: Authentication failed", status.as_u16()),
-                            auth_type: if status.as_u16() == 401 {
-                                AuthErrorType::InvalidApiKey
-                            } else {
-                                AuthErrorType::QuotaExceeded
-                            },
-                        });
-                    },
-                    500..=599 => {
-                        // Server errors are retryable
-                        if attempt == max_attempts {
-                            return Err(BlockProductionError::Http {
-                                source: response.error_for_status().unwrap_err(),
-                                context: Some(format!("Server error on attempt {attempt}")),
-                            });
-                        }
-                        continue;
-                    },
-                    _ => {
-                        return Err(BlockProductionError::Http {
-                            source: response.error_for_status().unwrap_err(),
-                            context: Some(format!("HTTP error {} on attempt {}", status.as_u16(), attempt)),
-                        });
+        // Check HTTP status
+        record.http_status = Some(status_code);
+        let status = reqwest::StatusCode::from_u16(status_code).map_err(|_| BlockProductionError::ResponseParsing {
+            reason: format!("Transport returned invalid HTTP status {status_code}"),
+            response_sample: None,
+            expected_structure: None,
+        })?;
+        let quota = ProviderQuota::from_headers(&headers);
+        if !status.is_success() {
+            error_history.push(format!("HTTP {} error on attempt {}", status.as_u16(), attempt));
+
+            warn!(
+                attempt = attempt,
+                status = status.as_u16(),
+                "HTTP request returned error status"
+            );
+
+            // Handle specific HTTP status codes
+            return Err(match status.as_u16() {
+                429 => {
+                    let retry_after = headers
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(parse_retry_after)
+                        .or(quota.reset_after);
+
+                    for rate_limiter in rate_limiters {
+                        rate_limiter.record_throttled(retry_after);
                     }
-                }
-            }
 
-            // Parse JSON response
-            let json_response: serde_json::Value = match response.json::<serde_json::Value>().await {
-                Ok(json) => {
                     debug!(
-                        attempt = attempt,
-                        response_size = json.to_string().len(),
-                        attempt_duration_ms = attempt_start.elapsed().as_millis(),
-                        "Successfully parsed JSON response"
-                    );
-                    json
-                },
-                Err(e) => {
-                    let error_msg = format!("JSON parsing error on attempt {attempt}: {e}");
-                    error_history.push(error_msg);
-                    
-                    error!(
-                        attempt = attempt,
-                        error = %e,
-                        "Failed to parse JSON response"
+                        retry_after_ms = retry_after.as_ref().map(std::time::Duration::as_millis),
+                        "Rate limit exceeded"
                     );
 
-                    return Err(BlockProductionError::ResponseParsing {
-                        reason: format!("Invalid JSON response: {e}"),
+                    // Report the most specific client-side quota, else what the provider told us
+                    let (requests, limit, window) = match rate_limiters.last() {
+                        Some(rate_limiter) => {
+                            let (limit, window) = rate_limiter.effective_quota();
+                            (rate_limiter.requests_in_window(), limit, window)
+                        },
+                        None => match quota.limit {
+                            Some(limit) => (
+                                limit.saturating_sub(quota.remaining.unwrap_or(0)),
+                                limit,
+                                quota.reset_after.unwrap_or(Duration::from_secs(1)),
+                            ),
+                            None => (0, 0, Duration::from_secs(60)),
+                        },
+                    };
+
+                    BlockProductionError::RateLimit {
+                        requests,
+                        window,
+                        limit,
+                        retry_after,
+                    }
+                },
+                401 | 403 => {
+                    error!(status = status.as_u16(), "Authentication failed");
+                    BlockProductionError::Auth {
+                        message: format!("HTTP {}: Authentication failed", status.as_u16()),
+                        auth_type: if status.as_u16() == 401 {
+                            AuthErrorType::InvalidApiKey
+                        } else {
+                            AuthErrorType::QuotaExceeded
+                        },
+                    }
+                },
+                code => match Self::status_error(&endpoint.label, status) {
+                    Some(source) => BlockProductionError::Http {
+                        source,
+                        context: Some(if status.is_server_error() {
+                            format!("Server error on attempt {attempt}")
+                        } else {
+                            format!("HTTP error {code} on attempt {attempt}")
+                        }),
+                    },
+                    None => BlockProductionError::ResponseParsing {
+                        reason: format!("Unexpected HTTP status {code}"),
                         response_sample: None,
-                        expected_structure: Some("Valid JSON object with 'result' field".to_string()),
-                    });
+                        expected_structure: None,
+                    },
+                },
+            });
+        }
+
+        for rate_limiter in rate_limiters {
+            rate_limiter.observe_quota(&quota);
+        }
+
+        let body = match response_body {
+            ResponseBody::Buffered(body) => body,
+            ResponseBody::Streaming(chunks) => {
+                let remaining = self.config.timeout.saturating_sub(attempt_start.elapsed());
+                let decoded = timeout(remaining, Self::decode_block_production_stream(chunks, &transport_request))
+                    .await
+                    .unwrap_or_else(|_| Err(BlockProductionError::Timeout {
+                        duration: self.config.timeout,
+                        operation: format!("RPC {method} request"),
+                        timeout_type: TimeoutType::Request,
+                    }));
+                let (envelope, received) = match decoded {
+                    Ok(decoded) => decoded,
+                    Err(e) => {
+                        error_history.push(format!("Streaming decode failed on attempt {attempt}: {e}"));
+                        warn!(attempt = attempt, error = %e, "Failed to decode streamed response");
+                        return Err(e);
+                    }
+                };
+                record.response_bytes = Some(received as u64);
+                debug!(
+                    attempt = attempt,
+                    response_size = received,
+                    attempt_duration_ms = attempt_start.elapsed().as_millis(),
+                    "Decoded streamed response"
+                );
+
+                if let Some(error) = &envelope.error {
+                    return Err(Self::rpc_error(error, method, attempt, error.to_string(), error_history));
                 }
-            };
+                let result = envelope.result.ok_or_else(|| BlockProductionError::ResponseParsing {
+                    reason: format!("{method} response is missing the 'result' field"),
+                    response_sample: None,
+                    expected_structure: Some("Valid JSON object with 'result' field".to_string()),
+                })?;
+                return Ok(RpcBody::BlockProduction {
+                    value: result.value,
+                    context_slot: result.context.map(|context| context.slot),
+                });
+            },
+        };
+        record.response_bytes = Some(body.len() as u64);
+        // Transports other than the default one may ignore the limit
+        if transport_request.max_response_bytes.is_some_and(|limit| body.len() > limit) {
+            return Err(transport_request.response_too_large(body.len()));
+        }
 
-            // Check for RPC errors in response
-            if let Some(error) = json_response.get("error") {
-                #[allow(clippy::cast_possible_truncation)]
-                let error_code = error.get("code").and_then(serde_json::Value::as_i64).unwrap_or(-1) as i32;
-                let error_message = error.get("message").and_then(|v| v.as_str()).unwrap_or("Unknown RPC error");
-                
-                let error_msg = format!("RPC error {error_code} on attempt {attempt}: {error_message}");
-                error_history.push(error_msg);
-                
-                warn!(
+        // Validate the JSON and look for an RPC error without building a Value tree
+        let envelope: RpcEnvelope<'_> = match serde_json::from_slice(&body) {
+            Ok(envelope) => {
+                debug!(
+                    attempt = attempt,
+                    response_size = body.len(),
+                    attempt_duration_ms = attempt_start.elapsed().as_millis(),
+                    "Successfully parsed JSON response"
+                );
+                envelope
+            },
+            Err(e) => {
+                error_history.push(format!("JSON parsing error on attempt {attempt}: {e}"));
+
+                error!(
                     attempt = attempt,
-                    rpc_error_code = error_code,
-                    rpc_error_message = error_message,
-                    "RPC returned error response"
+                    error = %e,
+                    "Failed to parse JSON response"
                 );
 
-                return Err(BlockProductionError::Rpc {
-                    code: error_code,
-                    message: error_message.to_string(),
-                    method: method.to_string(),
-                    raw_response: Some(json_response.to_string()),
+                return Err(BlockProductionError::ResponseParsing {
+                    reason: format!("Invalid JSON response: {e}"),
+                    response_sample: Some(String::from_utf8_lossy(&body[..body.len().min(200)]).into_owned()),
+                    expected_structure: Some("Valid JSON object with 'result' field".to_string()),
                 });
             }
+        };
 
-            // Success!
-            info!(
-                request_id = request_id,
-                method = method,
-                attempt = attempt,
-                total_duration_ms = total_start.elapsed().as_millis(),
-                attempt_duration_ms = attempt_start.elapsed().as_millis(),
-                "RPC request completed successfully"
-            );
-
-            return Ok(json_response);
+        // Check for RPC errors in response
+        if let Some(error) = envelope.error {
+            let error: serde_json::Value = serde_json::from_str(error.get())?;
+            let raw_response = String::from_utf8_lossy(&body).into_owned();
+            return Err(Self::rpc_error(&error, method, attempt, raw_response, error_history));
         }
 
-        // If we get here, all attempts failed
-        error!(
-            request_id = request_id,
-            method = method,
-            total_attempts = max_attempts,
-            total_duration_ms = total_start.elapsed().as_millis(),
-            "All retry attempts exhausted"
+        Ok(RpcBody::Buffered(body))
+    }
+
+    /// `Rpc` error for a JSON-RPC `error` object
+    fn rpc_error(
+        error: &serde_json::Value,
+        method: &str,
+        attempt: u32,
+        raw_response: String,
+        error_history: &mut Vec<String>,
+    ) -> BlockProductionError {
+        #[allow(clippy::cast_possible_truncation)]
+        let error_code = error.get("code").and_then(serde_json::Value::as_i64).unwrap_or(-1) as i32;
+        let error_message = error.get("message").and_then(|v| v.as_str()).unwrap_or("Unknown RPC error");
+
+        error_history.push(format!("RPC error {error_code} on attempt {attempt}: {error_message}"));
+
+        warn!(
+            attempt = attempt,
+            rpc_error_code = error_code,
+            rpc_error_message = error_message,
+            "RPC returned error response"
         );
 
-        Err(BlockProductionError::RetryExhausted {
-            attempts: max_attempts,
-            total_duration: total_start.elapsed(),
-            last_error: Box::new(BlockProductionError::Timeout {
-                duration: self.config.timeout,
-                operation: format!("RPC {method} request"),
-                timeout_type: TimeoutType::Request,
-            }),
-            error_history,
-        })
+        BlockProductionError::Rpc {
+            code: error_code,
+            message: error_message.to_string(),
+            method: method.to_string(),
+            raw_response: Some(raw_response),
+        }
     }
 
-    fn process_block_production_response(
-        response: serde_json::Value,
-        _start_time: Instant,
-    ) -> Result<BlockProductionData> {
-        let rpc_response: RpcResponse = serde_json::from_value(response)?;
-        let value = rpc_response.result.value;
+    /// Decode a getBlockProduction response while its body streams in
+    ///
+    /// Chunks are handed to a blocking deserializer, so `by_identity` is built
+    /// entry by entry as bytes arrive and the full body is never held in memory.
+    /// Returns the decoded response and the number of body bytes received.
+    async fn decode_block_production_stream(
+        mut chunks: BoxStream<'static, Result<Vec<u8>>>,
+        request: &TransportRequest<'_>,
+    ) -> Result<(StreamedEnvelope, usize)> {
+        let (sender, receiver) = tokio::sync::mpsc::channel(STREAMING_DECODE_BUFFERED_CHUNKS);
+        let decoder = tokio::task::spawn_blocking(move || {
+            serde_json::from_reader::<_, StreamedEnvelope>(std::io::BufReader::new(ChunkReader::new(receiver)))
+        });
+
+        let mut received = 0usize;
+        let mut stream_error = None;
+        while let Some(chunk) = chunks.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    stream_error = Some(e);
+                    break;
+                }
+            };
+            received += chunk.len();
+            if request.max_response_bytes.is_some_and(|limit| received > limit) {
+                stream_error = Some(request.response_too_large(received));
+                break;
+            }
+            // The decoder hung up early: the body is not valid JSON, reported below
+            if sender.send(chunk).await.is_err() {
+                break;
+            }
+        }
+        drop(sender);
+
+        let decoded = decoder.await.map_err(|e| BlockProductionError::Internal {
+            message: format!("Streaming decoder failed: {e}"),
+            location: Some("decode_block_production_stream".to_string()),
+            debug_context: None,
+        })?;
+        if let Some(e) = stream_error {
+            return Err(e);
+        }
+        let envelope = decoded.map_err(|e| BlockProductionError::ResponseParsing {
+            reason: format!("Invalid JSON response: {e}"),
+            response_sample: None,
+            expected_structure: Some("getBlockProduction response with 'result.value.byIdentity'".to_string()),
+        })?;
+        Ok((envelope, received))
+    }
+
+    /// Build statistics, distribution and health data from per-identity counts
+    fn build_block_production_data(value: BlockProductionValue) -> Result<BlockProductionData> {
         let slot_range = value.range.clone();
 
         if value.by_identity.is_empty() {
@@ -679,6 +2493,10 @@ impl BlockProductionClient {
             performance_snapshots,
             slot_range,
             fetched_at: timestamp,
+            epoch_context: None,
+            version_statistics: None,
+            range_coverage: None,
+            telemetry: None,
         })
     }
 
@@ -819,7 +2637,278 @@ impl BlockProductionClient {
             significant_skip_rate_95th_percentile,
             network_efficiency_percent,
             weighted_network_efficiency_percent,
+            stake_weighted: Self::calculate_stake_weighted_statistics(validators),
+        }
+    }
+
+    /// Calculate stake-weighted metrics for validators with joined stake data
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_stake_weighted_statistics(validators: &[ValidatorSkipRate]) -> Option<StakeWeightedStatistics> {
+        // Only validators that had leader slots contribute a meaningful skip rate
+        let mut staked: Vec<(&ValidatorSkipRate, u64)> = validators.iter()
+            .filter(|v| v.leader_slots > 0)
+            .filter_map(|v| v.stake_info.as_ref().map(|stake| (v, stake.activated_stake)))
+            .collect();
+
+        if staked.is_empty() {
+            return None;
+        }
+
+        let total_activated_stake: u64 = staked.iter().map(|(_, stake)| stake).sum();
+        let stake_percent = |predicate: &dyn Fn(&ValidatorSkipRate) -> bool| -> f64 {
+            if total_activated_stake == 0 {
+                return 0.0;
+            }
+            let matching: u64 = staked.iter()
+                .filter(|(v, _)| predicate(v))
+                .map(|(_, stake)| stake)
+                .sum();
+            (matching as f64 / total_activated_stake as f64) * 100.0
+        };
+
+        let concerning_stake_percent = stake_percent(&ValidatorSkipRate::is_concerning);
+        let offline_stake_percent = stake_percent(&ValidatorSkipRate::is_offline);
+        let delinquent_stake_percent = stake_percent(&|v| v.stake_info.as_ref().is_some_and(|s| s.delinquent));
+
+        let stake_weighted_skip_rate_percent = if total_activated_stake > 0 {
+            staked.iter()
+                .map(|(v, stake)| v.skip_rate_percent * *stake as f64)
+                .sum::<f64>() / total_activated_stake as f64
+        } else {
+            0.0
+        };
+
+        staked.sort_by(|a, b| a.0.skip_rate_percent.partial_cmp(&b.0.skip_rate_percent).unwrap_or(std::cmp::Ordering::Equal));
+
+        Some(StakeWeightedStatistics {
+            validators_with_stake: staked.len(),
+            total_activated_stake,
+            stake_weighted_skip_rate_percent,
+            stake_weighted_median_skip_rate_percent: Self::calculate_stake_weighted_percentile(&staked, total_activated_stake, 0.50),
+            stake_weighted_skip_rate_90th_percentile: Self::calculate_stake_weighted_percentile(&staked, total_activated_stake, 0.90),
+            stake_weighted_skip_rate_95th_percentile: Self::calculate_stake_weighted_percentile(&staked, total_activated_stake, 0.95),
+            concerning_stake_percent,
+            offline_stake_percent,
+            delinquent_stake_percent,
+        })
+    }
+
+    /// Skip rate at which the cumulative stake (sorted by skip rate) reaches the percentile
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_stake_weighted_percentile(sorted: &[(&ValidatorSkipRate, u64)], total_stake: u64, percentile: f64) -> f64 {
+        if sorted.is_empty() || total_stake == 0 {
+            return 0.0;
+        }
+
+        let threshold = total_stake as f64 * percentile;
+        let mut cumulative_stake = 0u64;
+        for (validator, stake) in sorted {
+            cumulative_stake += stake;
+            if cumulative_stake as f64 >= threshold {
+                return validator.skip_rate_percent;
+            }
+        }
+
+        sorted.last().map_or(0.0, |(validator, _)| validator.skip_rate_percent)
+    }
+
+    /// Project final skip rates with a beta-binomial model per validator
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_epoch_projection(
+        data: &BlockProductionData,
+        epoch_context: EpochContext,
+        remaining_leader_slots: &HashMap<String, Vec<u64>>,
+    ) -> EpochProjection {
+        let observed_leader_slots: u64 = data.validators.iter().map(|v| v.leader_slots).sum();
+        let observed_missed_slots: u64 = data.validators.iter().map(|v| v.missed_slots).sum();
+        let cluster_rate = if observed_leader_slots > 0 {
+            observed_missed_slots as f64 / observed_leader_slots as f64
+        } else {
+            0.0
+        };
+
+        let mut pubkeys: HashSet<&str> = data.validators.iter().map(|v| v.pubkey.as_str()).collect();
+        pubkeys.extend(remaining_leader_slots.keys().map(String::as_str));
+        let observed: HashMap<&str, &ValidatorSkipRate> = data.validators.iter()
+            .map(|v| (v.pubkey.as_str(), v))
+            .collect();
+
+        let mut cluster_remaining = 0;
+        let mut cluster_expected_misses = 0.0;
+        let mut cluster_variance = 0.0;
+
+        let mut validators: Vec<ValidatorSkipRateProjection> = pubkeys.into_iter()
+            .map(|pubkey| {
+                let (leader_slots, missed_slots) = observed
+                    .get(pubkey)
+                    .map_or((0, 0), |v| (v.leader_slots, v.missed_slots));
+                let remaining = remaining_leader_slots.get(pubkey).map_or(0, Vec::len) as u64;
+
+                let prior_weight = leader_slots as f64 + PROJECTION_PRIOR_SLOTS;
+                let expected_rate = (missed_slots as f64 + PROJECTION_PRIOR_SLOTS * cluster_rate) / prior_weight;
+                let expected_misses = remaining as f64 * expected_rate;
+                // Beta-binomial variance of the misses over the remaining slots
+                let variance = remaining as f64 * expected_rate * (1.0 - expected_rate)
+                    * (prior_weight + remaining as f64) / (prior_weight + 1.0);
+
+                cluster_remaining += remaining;
+                cluster_expected_misses += expected_misses;
+                cluster_variance += variance;
+
+                let (projected, low, high) = Self::projection_band(
+                    leader_slots + remaining,
+                    missed_slots,
+                    remaining,
+                    expected_misses,
+                    variance,
+                );
+
+                ValidatorSkipRateProjection {
+                    pubkey: pubkey.to_string(),
+                    current_leader_slots: leader_slots,
+                    current_missed_slots: missed_slots,
+                    current_skip_rate_percent: observed.get(pubkey).map_or(0.0, |v| v.skip_rate_percent),
+                    remaining_leader_slots: remaining,
+                    projected_missed_slots: missed_slots as f64 + expected_misses,
+                    projected_skip_rate_percent: projected,
+                    projected_skip_rate_low_percent: low,
+                    projected_skip_rate_high_percent: high,
+                }
+            })
+            .collect();
+
+        validators.sort_by(|a, b| {
+            b.projected_skip_rate_percent
+                .partial_cmp(&a.projected_skip_rate_percent)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.pubkey.cmp(&b.pubkey))
+        });
+
+        let (projected, low, high) = Self::projection_band(
+            observed_leader_slots + cluster_remaining,
+            observed_missed_slots,
+            cluster_remaining,
+            cluster_expected_misses,
+            cluster_variance,
+        );
+        let cluster = ClusterSkipRateProjection {
+            current_overall_skip_rate_percent: data.statistics.overall_skip_rate_percent,
+            remaining_leader_slots: cluster_remaining,
+            projected_overall_skip_rate_percent: projected,
+            projected_overall_skip_rate_low_percent: low,
+            projected_overall_skip_rate_high_percent: high,
+        };
+
+        EpochProjection {
+            epoch_context,
+            observed_slot: data.slot_range.last_slot,
+            validators,
+            cluster,
+        }
+    }
+
+    /// Convert expected future misses into (projected, low, high) skip rate percentages
+    ///
+    /// The band is clamped to what is still possible: misses so far are final and
+    /// at most every remaining slot can be missed.
+    #[allow(clippy::cast_precision_loss)]
+    fn projection_band(
+        total_slots: u64,
+        missed_slots: u64,
+        remaining: u64,
+        expected_misses: f64,
+        variance: f64,
+    ) -> (f64, f64, f64) {
+        if total_slots == 0 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let margin = PROJECTION_Z_SCORE * variance.sqrt();
+        let to_percent = |future_misses: f64| {
+            (missed_slots as f64 + future_misses.clamp(0.0, remaining as f64)) / total_slots as f64 * 100.0
+        };
+
+        (
+            to_percent(expected_misses),
+            to_percent(expected_misses - margin),
+            to_percent(expected_misses + margin),
+        )
+    }
+
+    /// Group validators by gossip software version and calculate per-version skip rates
+    #[allow(clippy::cast_precision_loss)]
+    fn calculate_version_statistics(validators: &[ValidatorSkipRate], nodes: &[ClusterNode]) -> Vec<VersionSkipRateStatistics> {
+        let node_versions: HashMap<&str, (Option<&str>, Option<u32>)> = nodes.iter()
+            .map(|node| (node.pubkey.as_str(), (node.version.as_deref(), node.feature_set)))
+            .collect();
+
+        let mut groups: HashMap<(String, Option<u32>), Vec<&ValidatorSkipRate>> = HashMap::new();
+        for validator in validators {
+            let (version, feature_set) = node_versions
+                .get(validator.pubkey.as_str())
+                .copied()
+                .unwrap_or((None, None));
+            groups
+                .entry((version.unwrap_or("unknown").to_string(), feature_set))
+                .or_default()
+                .push(validator);
         }
+
+        let network_leader_slots: u64 = validators.iter().map(|v| v.leader_slots).sum();
+
+        let mut version_statistics: Vec<VersionSkipRateStatistics> = groups.into_iter()
+            .map(|((version, feature_set), members)| {
+                let total_leader_slots: u64 = members.iter().map(|v| v.leader_slots).sum();
+                let total_missed_slots: u64 = members.iter().map(|v| v.missed_slots).sum();
+
+                let overall_skip_rate_percent = if total_leader_slots > 0 {
+                    (total_missed_slots as f64 / total_leader_slots as f64) * 100.0
+                } else {
+                    0.0
+                };
+
+                let leader_slot_share_percent = if network_leader_slots > 0 {
+                    (total_leader_slots as f64 / network_leader_slots as f64) * 100.0
+                } else {
+                    0.0
+                };
+
+                // Same significance weighting as the network-wide weighted skip rate
+                let significant: Vec<&&ValidatorSkipRate> = members.iter().filter(|v| v.is_significant()).collect();
+                let total_weight: f64 = significant.iter().map(|v| v.significance_weight()).sum();
+                let weighted_skip_rate_percent = if total_weight > 0.0 {
+                    significant.iter()
+                        .map(|v| v.skip_rate_percent * v.significance_weight())
+                        .sum::<f64>() / total_weight
+                } else {
+                    0.0
+                };
+
+                let mut skip_rates: Vec<f64> = members.iter().map(|v| v.skip_rate_percent).collect();
+                skip_rates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+                VersionSkipRateStatistics {
+                    version,
+                    feature_set,
+                    validator_count: members.len(),
+                    significant_validators: significant.len(),
+                    total_leader_slots,
+                    total_missed_slots,
+                    leader_slot_share_percent,
+                    overall_skip_rate_percent,
+                    weighted_skip_rate_percent,
+                    median_skip_rate_percent: Self::calculate_percentile(&skip_rates, 0.50),
+                    skip_rate_90th_percentile: Self::calculate_percentile(&skip_rates, 0.90),
+                    skip_rate_95th_percentile: Self::calculate_percentile(&skip_rates, 0.95),
+                }
+            })
+            .collect();
+
+        // Versions carrying the most leader slots first
+        version_statistics.sort_by(|a, b| {
+            b.total_leader_slots.cmp(&a.total_leader_slots).then_with(|| a.version.cmp(&b.version))
+        });
+        version_statistics
     }
 
     fn calculate_percentile(sorted_values: &[f64], percentile: f64) -> f64 {
@@ -861,11 +2950,9 @@ impl BlockProductionClient {
                 } else if label == "Dead (100%)" {
                     // Exact match for completely dead validators (missed all slots)
                     v.skip_rate_percent == 100.0
-                } else if label == "Failing (50-99%)" {
-                    // Range for failing but not completely dead
-                    v.skip_rate_percent >= min_percent && v.skip_rate_percent < max_percent
                 } else {
                     // Standard range: inclusive lower, exclusive upper
+                    // (also covers "Failing (50-99%)" - failing but not completely dead)
                     v.skip_rate_percent >= min_percent && v.skip_rate_percent < max_percent
                 }
             }).count();
@@ -876,8 +2963,6 @@ impl BlockProductionClient {
                         v.skip_rate_percent == 0.0
                     } else if label == "Dead (100%)" {
                         v.skip_rate_percent == 100.0
-                    } else if label == "Failing (50-99%)" {
-                        v.skip_rate_percent >= min_percent && v.skip_rate_percent < max_percent
                     } else {
                         v.skip_rate_percent >= min_percent && v.skip_rate_percent < max_percent
                     }
@@ -1118,6 +3203,7 @@ impl BlockProductionClient {
                 leader_slots: validator.leader_slots,
                 blocks_produced: validator.blocks_produced,
                 performance_category: category,
+                validator_info: validator.validator_info.clone(),
             }
         }).collect()
     }
@@ -1142,6 +3228,13 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the endpoint from a URL that embeds an API key, e.g. `Secret::from_env("HELIUS_RPC_URL")?`
+    #[must_use]
+    pub fn rpc_endpoint_secret(mut self, endpoint: &Secret) -> Self {
+        self.config.rpc_endpoint = endpoint.expose().to_string();
+        self
+    }
+
     #[must_use]
     pub const fn timeout(mut self, timeout: Duration) -> Self {
         self.config.timeout = timeout;
@@ -1154,18 +3247,55 @@ impl ClientBuilder {
         self
     }
 
+    /// Set the backoff, jitter and retry budget used between attempts
+    #[must_use]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config.retry_policy = policy;
+        self
+    }
+
     #[must_use]
     pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
-        use std::num::NonZeroU32;
-        use governor::{Quota, RateLimiter};
-        
-        if let Ok(non_zero) = NonZeroU32::try_from(requests_per_second) {
-            let quota = Quota::per_second(non_zero);
-            self.config.rate_limiter = Some(RateLimiter::direct(quota));
+        if let Some(limiter) = SharedRateLimiter::per_second(requests_per_second) {
+            self.config.rate_limiter = Some(limiter);
+        }
+        self
+    }
+
+    /// Start at `requests_per_second` and back off on 429s and provider quota headers
+    ///
+    /// Use `rate_limiter(SharedRateLimiter::adaptive(..))` to tune the back-off.
+    #[must_use]
+    pub fn adaptive_rate_limit(mut self, requests_per_second: u32) -> Self {
+        if let Some(limiter) = SharedRateLimiter::adaptive(requests_per_second, AdaptiveRateLimit::default()) {
+            self.config.rate_limiter = Some(limiter);
+        }
+        self
+    }
+
+    /// Share a rate limiter with other clients so they draw from one quota
+    #[must_use]
+    pub fn rate_limiter(mut self, limiter: SharedRateLimiter) -> Self {
+        self.config.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Limit one JSON-RPC method on the primary endpoint (requests per second)
+    #[must_use]
+    pub fn method_rate_limit(mut self, method: &str, requests_per_second: u32) -> Self {
+        if let Some(limiter) = SharedRateLimiter::per_second(requests_per_second) {
+            self.config.method_rate_limits.insert(method.to_string(), limiter);
         }
         self
     }
 
+    /// Share a per-method quota with other clients
+    #[must_use]
+    pub fn method_rate_limiter(mut self, method: &str, limiter: SharedRateLimiter) -> Self {
+        self.config.method_rate_limits.insert(method.to_string(), limiter);
+        self
+    }
+
     #[must_use]
     pub const fn max_concurrent_requests(mut self, max: usize) -> Self {
         self.config.max_concurrent_requests = max;
@@ -1174,7 +3304,133 @@ impl ClientBuilder {
 
     #[must_use]
     pub fn add_header(mut self, key: &str, value: &str) -> Self {
-        self.config.headers.insert(key.to_string(), value.to_string());
+        self.config.headers.insert(key.to_string(), Secret::new(value));
+        self
+    }
+
+    /// Add a credential header, e.g. `Secret::from_file("/run/secrets/rpc-token")?`
+    #[must_use]
+    pub fn add_secret_header(mut self, key: &str, value: Secret) -> Self {
+        self.config.headers.insert(key.to_string(), value);
+        self
+    }
+
+    /// Add a fallback endpoint used when the primary endpoint fails
+    #[must_use]
+    pub fn fallback_endpoint(mut self, endpoint: EndpointConfig) -> Self {
+        self.config.fallback_endpoints.push(endpoint);
+        self
+    }
+
+    #[must_use]
+    pub const fn validator_info_ttl(mut self, ttl: Duration) -> Self {
+        self.config.validator_info_ttl = ttl;
+        self
+    }
+
+    /// Cache block production responses per request for `ttl` (zero disables caching)
+    #[must_use]
+    pub const fn response_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.config.response_cache_ttl = ttl;
+        self
+    }
+
+    /// Enable the per-endpoint circuit breaker
+    #[must_use]
+    pub const fn circuit_breaker(mut self, breaker: CircuitBreakerConfig) -> Self {
+        self.config.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Send requests through a custom transport instead of the default reqwest client
+    #[must_use]
+    pub fn transport(mut self, transport: Arc<dyn RpcTransport>) -> Self {
+        self.config.transport = Some(transport);
+        self
+    }
+
+    /// Record every RPC interaction to a cassette file
+    #[must_use]
+    pub fn record_cassette(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.config.cassette = Some(CassetteMode::Record(path.into()));
+        self
+    }
+
+    /// Serve RPC responses from a recorded cassette instead of the network
+    #[must_use]
+    pub fn replay_cassette(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.config.cassette = Some(CassetteMode::Replay(path.into()));
+        self
+    }
+
+    /// Negotiate gzip, brotli and zstd compressed responses
+    ///
+    /// On by default with the crate's `compression` feature (a default feature);
+    /// only the default transport compresses.
+    #[must_use]
+    pub const fn compression(mut self, enabled: bool) -> Self {
+        self.config.compression = enabled;
+        self
+    }
+
+    /// Decode getBlockProduction responses incrementally as the body arrives
+    ///
+    /// Validators are added to `by_identity` while the response streams in, so the
+    /// full body is never buffered. Debug fetches always buffer to keep the raw data.
+    #[must_use]
+    pub const fn streaming_decode(mut self, enabled: bool) -> Self {
+        self.config.streaming_decode = enabled;
+        self
+    }
+
+    /// Reject responses larger than `bytes` with `BlockProductionError::ResponseTooLarge`
+    #[must_use]
+    pub const fn max_response_size(mut self, bytes: usize) -> Self {
+        self.config.max_response_size = Some(bytes);
+        self
+    }
+
+    /// Fail connection attempts after `timeout` with `TimeoutType::Connection`
+    ///
+    /// Transport settings (timeouts, proxy, TLS, HTTP version) apply to the default transport only.
+    #[must_use]
+    pub const fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Fail when a response stalls for `timeout` between reads, with `TimeoutType::Read`
+    #[must_use]
+    pub const fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.config.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Send all requests through an HTTP or SOCKS proxy
+    #[must_use]
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.config.proxy = Some(proxy);
+        self
+    }
+
+    /// Trust extra root certificates and present a client certificate for mutual TLS
+    #[must_use]
+    pub fn tls(mut self, tls: TlsConfig) -> Self {
+        self.config.tls = Some(tls);
+        self
+    }
+
+    /// Force HTTP/1.1 or HTTP/2 prior knowledge instead of negotiating the version
+    #[must_use]
+    pub const fn http_version(mut self, preference: HttpVersionPreference) -> Self {
+        self.config.http_version = preference;
+        self
+    }
+
+    /// Set the maximum slots per request when fetching slot ranges (0 disables chunking)
+    #[must_use]
+    pub const fn range_chunk_size(mut self, slots: u64) -> Self {
+        self.config.range_chunk_size = slots;
         self
     }
 
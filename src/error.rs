@@ -1,4 +1,5 @@
 use thiserror::Error;
+use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
 /// Comprehensive error types for the blocks production library
@@ -104,6 +105,17 @@ pub enum BlockProductionError {
         error_history: Vec<String>,
     },
 
+    /// Circuit breaker is open: the endpoint failed repeatedly and is not being called
+    #[error("Circuit breaker open for {endpoint} after {consecutive_failures} consecutive failures, retry in {retry_in:?}")]
+    CircuitOpen {
+        /// Endpoint whose circuit is open
+        endpoint: String,
+        /// Failures that opened the circuit
+        consecutive_failures: u32,
+        /// Time until the endpoint is probed again
+        retry_in: Duration,
+    },
+
     /// Invalid validator public key format
     #[error("Invalid validator public key: {pubkey}")]
     InvalidValidator {
@@ -135,6 +147,19 @@ pub enum BlockProductionError {
         expected_structure: Option<String>,
     },
 
+    /// Response body exceeded the configured maximum size
+    #[error("Response from {endpoint} to {method} exceeded {limit} bytes ({received} bytes received)")]
+    ResponseTooLarge {
+        /// RPC endpoint URL
+        endpoint: String,
+        /// The RPC method that was called
+        method: String,
+        /// Configured maximum response size in bytes
+        limit: usize,
+        /// Bytes received (or announced by Content-Length) when the response was rejected
+        received: usize,
+    },
+
     /// Internal library error (should not normally occur)
     #[error("Internal error: {message}")]
     Internal {
@@ -166,7 +191,8 @@ pub enum BlockProductionError {
 }
 
 /// Types of timeout errors
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum TimeoutType {
     /// Connection timeout (failed to establish connection)
     Connection,
@@ -190,7 +216,7 @@ pub enum AuthErrorType {
 }
 
 /// Error categories for filtering and handling
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ErrorCategory {
     /// Network-related errors (retryable)
     Network,
@@ -235,7 +261,8 @@ impl ErrorExt for BlockProductionError {
                 source.is_timeout() || source.is_connect() || 
                 source.status().map_or(true, |s| s.is_server_error())
             },
-            Self::Timeout { .. } | Self::RateLimit { .. } | Self::ConnectionFailed { .. } => true,
+            Self::Timeout { .. } | Self::RateLimit { .. } | Self::ConnectionFailed { .. } |
+            Self::CircuitOpen { .. } => true,
             Self::Rpc { code, .. } => {
                 // Some RPC errors are retryable
                 *code == -32603 || // Internal error
@@ -249,6 +276,7 @@ impl ErrorExt for BlockProductionError {
             Self::NoData { .. } |
             Self::RetryExhausted { .. } |
             Self::ResponseParsing { .. } |
+            Self::ResponseTooLarge { .. } |
             Self::Internal { .. } => false,
             Self::General { category, .. } => {
                 matches!(category, Some(ErrorCategory::Network | ErrorCategory::RateLimit))
@@ -267,7 +295,8 @@ impl ErrorExt for BlockProductionError {
     fn is_transient(&self) -> bool {
         match self {
             Self::Http { source, .. } => source.is_timeout() || source.is_connect(),
-            Self::Timeout { .. } | Self::RateLimit { .. } | Self::ConnectionFailed { .. } => true,
+            Self::Timeout { .. } | Self::RateLimit { .. } | Self::ConnectionFailed { .. } |
+            Self::CircuitOpen { .. } => true,
             _ => false,
         }
     }
@@ -277,6 +306,7 @@ impl ErrorExt for BlockProductionError {
             Self::RateLimit { retry_after, .. } => {
                 retry_after.or(Some(Duration::from_secs(60)))
             },
+            Self::CircuitOpen { retry_in, .. } => Some(*retry_in),
             Self::Timeout { .. } => Some(Duration::from_secs(5)),
             Self::ConnectionFailed { .. } => Some(Duration::from_secs(2)),
             Self::Http { source, .. } if source.is_timeout() => Some(Duration::from_secs(3)),
@@ -287,14 +317,15 @@ impl ErrorExt for BlockProductionError {
     
     fn category(&self) -> ErrorCategory {
         match self {
-            Self::Http { .. } | Self::ConnectionFailed { .. } | Self::Timeout { .. } => {
+            Self::Http { .. } | Self::ConnectionFailed { .. } | Self::Timeout { .. } |
+            Self::CircuitOpen { .. } => {
                 ErrorCategory::Network
             },
             Self::Config { .. } => ErrorCategory::Configuration,
             Self::InvalidSlotRange { .. } | Self::InvalidValidator { .. } => {
                 ErrorCategory::Validation
             },
-            Self::Rpc { .. } | Self::ResponseParsing { .. } => ErrorCategory::Rpc,
+            Self::Rpc { .. } | Self::ResponseParsing { .. } | Self::ResponseTooLarge { .. } => ErrorCategory::Rpc,
             Self::RateLimit { .. } => ErrorCategory::RateLimit,
             Self::Auth { .. } => ErrorCategory::Authentication,
             Self::Json { .. } | Self::NoData { .. } | Self::RetryExhausted { .. } | 
@@ -325,6 +356,14 @@ impl ErrorExt for BlockProductionError {
             Self::Config { suggestion: Some(suggestion), .. } => {
                 hints.push(suggestion.clone());
             },
+            Self::ResponseTooLarge { limit, .. } => {
+                hints.push(format!("Raise max_response_size above {limit} bytes"));
+                hints.push("Request a narrower slot range or enable range_chunk_size".to_string());
+            },
+            Self::CircuitOpen { endpoint, retry_in, .. } => {
+                hints.push(format!("{endpoint} is failing; it will be probed again in {retry_in:?}"));
+                hints.push("Add fallback endpoints so requests can fail over".to_string());
+            },
             Self::Auth { auth_type, .. } => {
                 match auth_type {
                     AuthErrorType::MissingApiKey => {
@@ -379,9 +418,10 @@ impl From<tokio::time::error::Elapsed> for BlockProductionError {
 }
 
 impl From<reqwest::Error> for BlockProductionError {
+    /// Drops the request URL, which may carry an API key
     fn from(error: reqwest::Error) -> Self {
         Self::Http {
-            source: error,
+            source: error.without_url(),
             context: None,
         }
     }
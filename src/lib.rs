@@ -12,7 +12,7 @@
 //! 
 //! ## Quick Start
 //! 
-//! ```rust
+//! ```rust,no_run
 //! use blocks_production_lib::BlockProductionClient;
 //! 
 //! #[tokio::main]
@@ -48,12 +48,20 @@ pub mod config;
 pub mod error;
 pub mod types;
 pub mod logging;
+pub mod query;
+pub mod transport;
+pub mod cassette;
+pub mod secret;
 
 // Re-export main types for convenience
 pub use client::{BlockProductionClient, ClientBuilder};
-pub use config::{ClientConfig, ClientConfigBuilder};
-pub use error::{BlockProductionError, Result, ErrorExt};
+pub use config::{ClientConfig, ClientConfigBuilder, EndpointConfig, RetryPolicy, CategoryRetryPolicy, CircuitBreakerConfig, SharedRateLimiter, AdaptiveRateLimit, ProxyConfig, TlsConfig, HttpVersionPreference};
+pub use error::{BlockProductionError, Result, ErrorExt, ErrorCategory};
 pub use logging::{init_logging, init_test_logging, LoggingConfig, LogFormat};
+pub use query::{ValidatorQuery, ValidatorSortField, SortOrder};
+pub use secret::{Secret, redact_url};
+pub use transport::{RpcTransport, ReqwestTransport, MockTransport, FileTransport, TransportRequest, TransportResponse, StreamingResponse};
+pub use cassette::{CassetteMode, CassetteInteraction, RecordedOutcome, RecordingTransport, ReplayTransport};
 
 /// Test utilities for mocking and testing
 #[cfg(test)]
@@ -62,26 +70,24 @@ pub mod test_utils {
 
     /// Creates mock block production data for testing
     pub fn create_mock_block_production_data() -> BlockProductionData {
-        let mut validators = Vec::new();
-        
         // Create test validators with different performance levels
-        validators.push(ValidatorSkipRate::new(
-            "perfect_validator".to_string(),
-            100,
-            100,
-        ));
-        
-        validators.push(ValidatorSkipRate::new(
-            "good_validator".to_string(),
-            100,
-            98,
-        ));
-        
-        validators.push(ValidatorSkipRate::new(
-            "concerning_validator".to_string(),
-            100,
-            85,
-        ));
+        let validators = vec![
+            ValidatorSkipRate::new(
+                "perfect_validator".to_string(),
+                100,
+                100,
+            ),
+            ValidatorSkipRate::new(
+                "good_validator".to_string(),
+                100,
+                98,
+            ),
+            ValidatorSkipRate::new(
+                "concerning_validator".to_string(),
+                100,
+                85,
+            ),
+        ];
 
         let slot_range = SlotRange {
             first_slot: 1000,
@@ -112,6 +118,7 @@ pub mod test_utils {
             significant_skip_rate_95th_percentile: 15.0,
             network_efficiency_percent: 95.0,
             weighted_network_efficiency_percent: 95.0,
+            stake_weighted: None,
         };
 
         let distribution = SkipRateDistribution {
@@ -169,6 +176,10 @@ pub mod test_utils {
             performance_snapshots: vec![],
             slot_range,
             fetched_at: chrono::Utc::now(),
+            epoch_context: None,
+            version_statistics: None,
+            range_coverage: None,
+            telemetry: None,
         }
     }
 
@@ -195,7 +206,7 @@ pub mod test_utils {
 
     /// Creates mock percentile data for testing
     pub fn create_mock_percentile_data() -> Vec<PercentileData> {
-        let _validators = vec![
+        let _validators = [
             ("validator1".to_string(), 5.0),
             ("validator2".to_string(), 10.0),
             ("validator3".to_string(), 15.0),
@@ -223,7 +234,13 @@ pub use types::{
     BlockProductionData, BlockProductionDataDebug, BlockProductionRequest,
     SkipRateStatistics, SlotRange, ValidatorSkipRate, ResponseMetadata,
     SkipRateDistribution, NetworkHealthSummary, ValidatorPerformanceSnapshot,
-    ValidatorPerformanceCategory, AlertSeverity,
+    ValidatorPerformanceCategory, AlertSeverity, EpochInfo, EpochSchedule, EpochContext,
+    StakeWeightedStatistics, ValidatorStakeInfo, VoteAccounts, ValidatorInfo,
+    ClusterNode, VersionSkipRateStatistics, EpochHistory, ValidatorEpochHistory,
+    ValidatorEpochSkipRate, UnavailableEpoch, RangeCoverage, FailedRangeChunk,
+    EpochProjection, ValidatorSkipRateProjection, ClusterSkipRateProjection,
+    EndpointDiagnostics, RpcCallDiagnostic, RpcVersionInfo, SolanaCluster, EndpointHealth, CircuitState, RequestTelemetry, RpcAttempt,
+    ConsensusResult, ConsensusReport, ProviderResponse, ProviderCounts, ValidatorDisagreement,
 };
 
 #[cfg(test)]
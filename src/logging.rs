@@ -211,8 +211,8 @@ mod tests {
 
         assert_eq!(config.level, "debug");
         assert_eq!(config.format, LogFormat::Json);
-        assert_eq!(config.include_location, true);
-        assert_eq!(config.include_spans, false);
+        assert!(config.include_location);
+        assert!(!config.include_spans);
     }
 
     #[test]
@@ -220,8 +220,8 @@ mod tests {
         let config = LoggingConfig::default();
         assert_eq!(config.level, "info");
         assert_eq!(config.format, LogFormat::Pretty);
-        assert_eq!(config.include_location, false);
-        assert_eq!(config.include_spans, true);
+        assert!(!config.include_location);
+        assert!(config.include_spans);
     }
 
     #[test]
@@ -242,7 +242,7 @@ mod tests {
     #[test]
     fn test_log_format_clone() {
         let original = LogFormat::Json;
-        let cloned = original.clone();
+        let cloned = original;
         assert_eq!(original, cloned);
     }
 
@@ -265,8 +265,8 @@ mod tests {
 
         assert_eq!(config.level, "warn");
         assert_eq!(config.format, LogFormat::Compact);
-        assert_eq!(config.include_location, true);
-        assert_eq!(config.include_spans, false);
+        assert!(config.include_location);
+        assert!(!config.include_spans);
     }
 
     #[test]
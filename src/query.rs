@@ -0,0 +1,313 @@
+use crate::types::{BlockProductionData, ValidatorPerformanceCategory, ValidatorSkipRate};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+/// Field to sort validators by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidatorSortField {
+    /// Validator public key
+    Pubkey,
+    /// Number of leader slots assigned
+    LeaderSlots,
+    /// Number of blocks produced
+    BlocksProduced,
+    /// Number of missed slots
+    MissedSlots,
+    /// Skip rate percentage
+    SkipRate,
+    /// Significance weight used by the weighted statistics
+    SignificanceWeight,
+    /// Activated stake (validators without stake data sort as zero)
+    ActivatedStake,
+}
+
+/// Sort direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+type ValidatorFilter<'a> = Box<dyn Fn(&ValidatorSkipRate) -> bool + 'a>;
+
+/// Composable query over the validators of a `BlockProductionData` snapshot
+///
+/// Filters are combined with AND, then the result is sorted (stable, so the
+/// snapshot order breaks ties) and paginated.
+pub struct ValidatorQuery<'a> {
+    validators: &'a [ValidatorSkipRate],
+    filters: Vec<ValidatorFilter<'a>>,
+    sort: Option<(ValidatorSortField, SortOrder)>,
+    offset: usize,
+    limit: Option<usize>,
+}
+
+impl<'a> ValidatorQuery<'a> {
+    /// Start a query over a slice of validators
+    #[must_use]
+    pub fn new(validators: &'a [ValidatorSkipRate]) -> Self {
+        Self {
+            validators,
+            filters: Vec::new(),
+            sort: None,
+            offset: 0,
+            limit: None,
+        }
+    }
+
+    /// Keep validators matching a custom predicate
+    #[must_use]
+    pub fn filter(mut self, predicate: impl Fn(&ValidatorSkipRate) -> bool + 'a) -> Self {
+        self.filters.push(Box::new(predicate));
+        self
+    }
+
+    /// Keep validators in the given performance category
+    #[must_use]
+    pub fn category(self, category: ValidatorPerformanceCategory) -> Self {
+        self.categories(vec![category])
+    }
+
+    /// Keep validators in any of the given performance categories
+    #[must_use]
+    pub fn categories(self, categories: Vec<ValidatorPerformanceCategory>) -> Self {
+        self.filter(move |v| {
+            categories.contains(&ValidatorPerformanceCategory::from_skip_rate(v.skip_rate_percent, v.leader_slots))
+        })
+    }
+
+    /// Keep validators with 0% skip rate
+    #[must_use]
+    pub fn perfect(self) -> Self {
+        self.filter(ValidatorSkipRate::is_perfect)
+    }
+
+    /// Keep validators with concerning skip rates (> 5%)
+    #[must_use]
+    pub fn concerning(self) -> Self {
+        self.filter(ValidatorSkipRate::is_concerning)
+    }
+
+    /// Keep completely offline validators (100% skip rate)
+    #[must_use]
+    pub fn offline(self) -> Self {
+        self.filter(ValidatorSkipRate::is_offline)
+    }
+
+    /// Drop completely offline validators
+    #[must_use]
+    pub fn exclude_offline(self) -> Self {
+        self.filter(|v| !v.is_offline())
+    }
+
+    /// Keep significant validators (>= 50 slots)
+    #[must_use]
+    pub fn significant(self) -> Self {
+        self.filter(ValidatorSkipRate::is_significant)
+    }
+
+    /// Keep high-activity validators (> 1000 slots)
+    #[must_use]
+    pub fn high_activity(self) -> Self {
+        self.filter(ValidatorSkipRate::is_high_stake)
+    }
+
+    /// Keep validators with at least `slots` leader slots
+    #[must_use]
+    pub fn min_leader_slots(self, slots: u64) -> Self {
+        self.filter(move |v| v.leader_slots >= slots)
+    }
+
+    /// Keep validators with at most `slots` leader slots
+    #[must_use]
+    pub fn max_leader_slots(self, slots: u64) -> Self {
+        self.filter(move |v| v.leader_slots <= slots)
+    }
+
+    /// Keep validators with a skip rate of at least `percent`
+    #[must_use]
+    pub fn min_skip_rate(self, percent: f64) -> Self {
+        self.filter(move |v| v.skip_rate_percent >= percent)
+    }
+
+    /// Keep validators with a skip rate of at most `percent`
+    #[must_use]
+    pub fn max_skip_rate(self, percent: f64) -> Self {
+        self.filter(move |v| v.skip_rate_percent <= percent)
+    }
+
+    /// Keep validators with a skip rate strictly above `percent`
+    #[must_use]
+    pub fn skip_rate_above(self, percent: f64) -> Self {
+        self.filter(move |v| v.skip_rate_percent > percent)
+    }
+
+    /// Keep validators with a skip rate strictly below `percent`
+    #[must_use]
+    pub fn skip_rate_below(self, percent: f64) -> Self {
+        self.filter(move |v| v.skip_rate_percent < percent)
+    }
+
+    /// Keep only the given validators
+    #[must_use]
+    pub fn pubkeys<I, S>(self, pubkeys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let pubkeys: HashSet<String> = pubkeys.into_iter().map(Into::into).collect();
+        self.filter(move |v| pubkeys.contains(&v.pubkey))
+    }
+
+    /// Drop the given validators
+    #[must_use]
+    pub fn exclude_pubkeys<I, S>(self, pubkeys: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let pubkeys: HashSet<String> = pubkeys.into_iter().map(Into::into).collect();
+        self.filter(move |v| !pubkeys.contains(&v.pubkey))
+    }
+
+    /// Sort the result by a field
+    #[must_use]
+    pub const fn sort_by(mut self, field: ValidatorSortField, order: SortOrder) -> Self {
+        self.sort = Some((field, order));
+        self
+    }
+
+    /// Skip the first `offset` results
+    #[must_use]
+    pub const fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Return at most `limit` results
+    #[must_use]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Return the first `n` results (alias for `limit`)
+    #[must_use]
+    pub const fn top(self, n: usize) -> Self {
+        self.limit(n)
+    }
+
+    /// Return one page of results (pages start at 0)
+    #[must_use]
+    pub const fn page(self, page: usize, page_size: usize) -> Self {
+        self.offset(page.saturating_mul(page_size)).limit(page_size)
+    }
+
+    /// Run the query
+    #[must_use]
+    pub fn collect(self) -> Vec<&'a ValidatorSkipRate> {
+        let mut matches: Vec<&'a ValidatorSkipRate> = self.validators.iter()
+            .filter(|v| self.filters.iter().all(|filter| filter(v)))
+            .collect();
+
+        if let Some((field, order)) = self.sort {
+            matches.sort_by(|a, b| {
+                let ordering = Self::compare(a, b, field);
+                match order {
+                    SortOrder::Ascending => ordering,
+                    SortOrder::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        matches.into_iter()
+            .skip(self.offset)
+            .take(self.limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Run the query and clone the matching validators
+    #[must_use]
+    pub fn to_vec(self) -> Vec<ValidatorSkipRate> {
+        self.collect().into_iter().cloned().collect()
+    }
+
+    /// Number of validators matching the filters (ignores pagination)
+    #[must_use]
+    pub fn count(&self) -> usize {
+        self.validators.iter()
+            .filter(|v| self.filters.iter().all(|filter| filter(v)))
+            .count()
+    }
+
+    /// First result of the query
+    #[must_use]
+    pub fn first(self) -> Option<&'a ValidatorSkipRate> {
+        self.limit(1).collect().into_iter().next()
+    }
+
+    fn compare(a: &ValidatorSkipRate, b: &ValidatorSkipRate, field: ValidatorSortField) -> Ordering {
+        match field {
+            ValidatorSortField::Pubkey => a.pubkey.cmp(&b.pubkey),
+            ValidatorSortField::LeaderSlots => a.leader_slots.cmp(&b.leader_slots),
+            ValidatorSortField::BlocksProduced => a.blocks_produced.cmp(&b.blocks_produced),
+            ValidatorSortField::MissedSlots => a.missed_slots.cmp(&b.missed_slots),
+            ValidatorSortField::SkipRate => a.skip_rate_percent
+                .partial_cmp(&b.skip_rate_percent)
+                .unwrap_or(Ordering::Equal),
+            ValidatorSortField::SignificanceWeight => a.significance_weight()
+                .partial_cmp(&b.significance_weight())
+                .unwrap_or(Ordering::Equal),
+            ValidatorSortField::ActivatedStake => {
+                let stake = |v: &ValidatorSkipRate| v.stake_info.as_ref().map_or(0, |s| s.activated_stake);
+                stake(a).cmp(&stake(b))
+            }
+        }
+    }
+}
+
+impl BlockProductionData {
+    /// Start a query over this snapshot's validators
+    #[must_use]
+    pub fn query(&self) -> ValidatorQuery<'_> {
+        ValidatorQuery::new(&self.validators)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::create_mock_block_production_data;
+
+    fn pubkeys(validators: Vec<&ValidatorSkipRate>) -> Vec<&str> {
+        validators.into_iter().map(|v| v.pubkey.as_str()).collect()
+    }
+
+    #[test]
+    fn test_query_filters_and_sorting() {
+        let data = create_mock_block_production_data();
+
+        assert_eq!(pubkeys(data.query().concerning().collect()), vec!["concerning_validator"]);
+        assert_eq!(pubkeys(data.query().perfect().collect()), vec!["perfect_validator"]);
+        assert_eq!(data.query().category(ValidatorPerformanceCategory::Good).count(), 1);
+        assert_eq!(data.query().skip_rate_above(0.0).max_skip_rate(5.0).count(), 1);
+        assert_eq!(data.query().exclude_pubkeys(["good_validator"]).count(), 2);
+
+        let worst_first = data.query()
+            .sort_by(ValidatorSortField::SkipRate, SortOrder::Descending)
+            .collect();
+        assert_eq!(pubkeys(worst_first), vec!["concerning_validator", "good_validator", "perfect_validator"]);
+    }
+
+    #[test]
+    fn test_query_pagination() {
+        let data = create_mock_block_production_data();
+        let by_pubkey = || data.query().sort_by(ValidatorSortField::Pubkey, SortOrder::Ascending);
+
+        assert_eq!(pubkeys(by_pubkey().page(0, 2).collect()), vec!["concerning_validator", "good_validator"]);
+        assert_eq!(pubkeys(by_pubkey().page(1, 2).collect()), vec!["perfect_validator"]);
+        assert_eq!(by_pubkey().top(1).to_vec()[0].pubkey, "concerning_validator");
+        assert_eq!(by_pubkey().offset(1).first().unwrap().pubkey, "good_validator");
+        assert_eq!(by_pubkey().page(5, 2).count(), 3);
+    }
+}
@@ -0,0 +1,604 @@
+use crate::config::{ClientConfig, HttpVersionPreference, ProxyConfig, TlsConfig};
+use crate::error::{BlockProductionError, Result, TimeoutType};
+use crate::secret::redact_url;
+use futures::future::BoxFuture;
+use futures::stream::{self, BoxStream};
+use reqwest::header::HeaderMap;
+use serde_json::json;
+use std::collections::{HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A JSON-RPC request ready to be sent to one endpoint
+#[derive(Debug, Clone, Copy)]
+pub struct TransportRequest<'a> {
+    /// Endpoint URL
+    pub endpoint: &'a str,
+    /// Headers configured for this endpoint
+    pub headers: &'a HeaderMap,
+    /// JSON-RPC request body
+    pub body: &'a serde_json::Value,
+    /// Configured request timeout (the client also enforces it around `send`)
+    pub timeout: Duration,
+    /// Stop reading the body once it exceeds this many bytes (the client also checks it)
+    pub max_response_bytes: Option<usize>,
+}
+
+impl TransportRequest<'_> {
+    /// JSON-RPC method of the request
+    #[must_use]
+    pub fn method(&self) -> &str {
+        self.body.get("method").and_then(|v| v.as_str()).unwrap_or("unknown")
+    }
+
+    /// `ResponseTooLarge` error for this request
+    #[must_use]
+    pub fn response_too_large(&self, received: usize) -> BlockProductionError {
+        BlockProductionError::ResponseTooLarge {
+            endpoint: redact_url(self.endpoint),
+            method: self.method().to_string(),
+            limit: self.max_response_bytes.unwrap_or(received),
+            received,
+        }
+    }
+}
+
+/// Raw response returned by a transport
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers (`Retry-After` is read from here on 429)
+    pub headers: HeaderMap,
+    /// Response body
+    pub body: Vec<u8>,
+}
+
+impl TransportResponse {
+    #[must_use]
+    pub fn new(status: u16, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body,
+        }
+    }
+
+    /// 200 response with a JSON body
+    #[must_use]
+    pub fn json(body: &serde_json::Value) -> Self {
+        Self::new(200, body.to_string().into_bytes())
+    }
+
+    /// Add a response header (invalid names or values are ignored)
+    #[must_use]
+    pub fn with_header(mut self, key: &str, value: &str) -> Self {
+        if let (Ok(name), Ok(value)) = (
+            reqwest::header::HeaderName::from_bytes(key.as_bytes()),
+            reqwest::header::HeaderValue::from_str(value),
+        ) {
+            self.headers.insert(name, value);
+        }
+        self
+    }
+}
+
+/// Response whose body is read chunk by chunk
+pub struct StreamingResponse {
+    /// HTTP status code
+    pub status: u16,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Body chunks in order
+    pub body: BoxStream<'static, Result<Vec<u8>>>,
+}
+
+impl std::fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+impl From<TransportResponse> for StreamingResponse {
+    fn from(response: TransportResponse) -> Self {
+        Self {
+            status: response.status,
+            headers: response.headers,
+            body: Box::pin(stream::once(async move { Ok(response.body) })),
+        }
+    }
+}
+
+/// Sends JSON-RPC requests for `BlockProductionClient`
+///
+/// Implementations only move bytes. Status handling, retries, rate limiting,
+/// failover and JSON-RPC errors stay in the client. Failures to get a response
+/// should be reported as `Timeout`, `ConnectionFailed` or `Http` so the retry
+/// policy can classify them.
+pub trait RpcTransport: Send + Sync + std::fmt::Debug {
+    /// Send one request and return the raw response
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>>;
+
+    /// Send one request and return the body as a stream of chunks
+    ///
+    /// Used by the client's streaming decoder. The default buffers the whole
+    /// body with `send` and yields it as a single chunk.
+    fn send_streaming<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<StreamingResponse>> {
+        Box::pin(async move { self.send(request).await.map(StreamingResponse::from) })
+    }
+}
+
+/// Default transport over a pooled `reqwest::Client`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+    timeouts: PhaseTimeouts,
+}
+
+/// Connect and read timeouts set on the reqwest client
+#[derive(Debug, Clone, Copy, Default)]
+struct PhaseTimeouts {
+    connect: Option<Duration>,
+    read: Option<Duration>,
+}
+
+impl PhaseTimeouts {
+    /// Classify a reqwest timeout by the phase that ran out
+    ///
+    /// A timeout while connecting is a `Connection` timeout. Any other one that
+    /// fires before the overall request timeout came from the read timeout.
+    fn error(self, error: &reqwest::Error, request_timeout: Duration, started: Instant, operation: String) -> BlockProductionError {
+        let (duration, timeout_type) = match (self.connect, self.read) {
+            (Some(connect), _) if error.is_connect() => (connect, TimeoutType::Connection),
+            (_, Some(read)) if started.elapsed() < request_timeout => (read, TimeoutType::Read),
+            _ => (request_timeout, TimeoutType::Request),
+        };
+        BlockProductionError::Timeout {
+            duration,
+            operation,
+            timeout_type,
+        }
+    }
+}
+
+impl ReqwestTransport {
+    /// Create a transport with the library's default HTTP client settings
+    pub fn new(timeout: Duration) -> Result<Self> {
+        Self::with_compression(timeout, cfg!(feature = "compression"))
+    }
+
+    /// Create a transport, negotiating gzip, brotli and zstd responses when `compression` is set
+    ///
+    /// Compression needs the crate's `compression` feature; without it
+    /// responses are requested uncompressed and a warning is logged.
+    pub fn with_compression(timeout: Duration, compression: bool) -> Result<Self> {
+        Self::from_config(&ClientConfig {
+            timeout,
+            compression,
+            ..ClientConfig::default()
+        })
+    }
+
+    /// Create a transport with the timeouts, proxy, TLS and HTTP version settings of `config`
+    pub fn from_config(config: &ClientConfig) -> Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .user_agent("blocks-production-lib/0.1.0")
+            .pool_idle_timeout(Duration::from_secs(90))
+            .pool_max_idle_per_host(10);
+
+        #[cfg(feature = "compression")]
+        {
+            builder = builder.gzip(config.compression).brotli(config.compression).zstd(config.compression);
+        }
+        #[cfg(not(feature = "compression"))]
+        if config.compression {
+            tracing::warn!("Response compression requested without the `compression` feature; responses will be uncompressed");
+        }
+
+        if let Some(connect_timeout) = config.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+        if let Some(read_timeout) = config.read_timeout {
+            builder = builder.read_timeout(read_timeout);
+        }
+        if let Some(proxy) = &config.proxy {
+            builder = builder.proxy(Self::build_proxy(proxy)?);
+        }
+        if let Some(tls) = &config.tls {
+            builder = Self::apply_tls(builder, tls)?;
+        }
+        builder = match config.http_version {
+            HttpVersionPreference::Auto => builder,
+            HttpVersionPreference::Http1Only => builder.http1_only(),
+            HttpVersionPreference::Http2PriorKnowledge => builder.http2_prior_knowledge(),
+        };
+
+        let client = builder
+            .build()
+            .map_err(|e| BlockProductionError::config_error(
+                &format!("Failed to create HTTP client: {}", e.without_url()),
+                None,
+                Some("Check timeout, proxy, TLS and header configuration"),
+            ))?;
+        Ok(Self {
+            client,
+            timeouts: PhaseTimeouts {
+                connect: config.connect_timeout,
+                read: config.read_timeout,
+            },
+        })
+    }
+
+    /// Use an existing `reqwest::Client` (custom TLS, proxies, middleware-free stacks)
+    #[must_use]
+    pub const fn from_client(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            timeouts: PhaseTimeouts {
+                connect: None,
+                read: None,
+            },
+        }
+    }
+
+    fn build_proxy(config: &ProxyConfig) -> Result<reqwest::Proxy> {
+        let scheme = config.scheme();
+        if !["http", "https", "socks5", "socks5h"].contains(&scheme.as_str()) {
+            return Err(BlockProductionError::config_error(
+                &format!("Unsupported proxy URL {}", redact_url(config.url.expose())),
+                Some("proxy"),
+                Some("Use an http://, https://, socks5:// or socks5h:// URL"),
+            ));
+        }
+        if scheme.starts_with("socks") && cfg!(not(feature = "socks")) {
+            return Err(BlockProductionError::config_error(
+                &format!("Proxy scheme {scheme} needs the `socks` feature"),
+                Some("proxy"),
+                Some("Enable the crate's `socks` feature or use an HTTP proxy"),
+            ));
+        }
+
+        let mut proxy = reqwest::Proxy::all(config.url.expose())
+            .map_err(|e| BlockProductionError::config_error(
+                &format!("Invalid proxy URL {}: {}", redact_url(config.url.expose()), e.without_url()),
+                Some("proxy"),
+                Some("Use an http://, https://, socks5:// or socks5h:// URL"),
+            ))?;
+        if let Some((username, password)) = &config.basic_auth {
+            proxy = proxy.basic_auth(username, password.expose());
+        }
+        if !config.no_proxy.is_empty() {
+            proxy = proxy.no_proxy(reqwest::NoProxy::from_string(&config.no_proxy.join(",")));
+        }
+        Ok(proxy)
+    }
+
+    fn apply_tls(mut builder: reqwest::ClientBuilder, tls: &TlsConfig) -> Result<reqwest::ClientBuilder> {
+        for pem in &tls.root_certificates {
+            let certificate = reqwest::Certificate::from_pem(pem)
+                .map_err(|e| BlockProductionError::config_error(
+                    &format!("Invalid root certificate: {e}"),
+                    Some("tls.root_certificates"),
+                    Some("Provide PEM-encoded CA certificates"),
+                ))?;
+            builder = builder.add_root_certificate(certificate);
+        }
+        if tls.disable_built_in_roots {
+            builder = builder.tls_built_in_root_certs(false);
+        }
+
+        match (&tls.client_certificate, &tls.client_key) {
+            (Some(certificate), Some(key)) => {
+                // Only the error kind is reported; the message could quote key material
+                let identity = reqwest::Identity::from_pkcs8_pem(certificate, key.expose().as_bytes())
+                    .map_err(|_| BlockProductionError::config_error(
+                        "Invalid client certificate or key",
+                        Some("tls.client_certificate"),
+                        Some("Provide a PEM certificate chain and a PKCS#8 PEM private key"),
+                    ))?;
+                builder = builder.identity(identity);
+            }
+            (None, None) => {}
+            _ => {
+                return Err(BlockProductionError::config_error(
+                    "Mutual TLS needs both a client certificate and a key",
+                    Some("tls.client_certificate"),
+                    Some("Set both with TlsConfig::client_identity"),
+                ));
+            }
+        }
+        Ok(builder)
+    }
+
+    fn map_error(&self, error: reqwest::Error, request: &TransportRequest<'_>, started: Instant) -> BlockProductionError {
+        if error.is_timeout() {
+            self.timeouts.error(&error, request.timeout, started, format!("RPC {} request", request.method()))
+        } else if error.is_connect() {
+            BlockProductionError::ConnectionFailed {
+                endpoint: redact_url(request.endpoint),
+                source: Box::new(error.without_url()),
+                endpoint_reachable: None,
+            }
+        } else {
+            BlockProductionError::Http {
+                source: error.without_url(),
+                context: Some(format!("RPC {} request", request.method())),
+            }
+        }
+    }
+
+    async fn post(&self, request: &TransportRequest<'_>, started: Instant) -> Result<reqwest::Response> {
+        let response = self.client
+            .post(request.endpoint)
+            .headers(request.headers.clone())
+            .json(request.body)
+            .send()
+            .await
+            .map_err(|e| self.map_error(e, request, started))?;
+
+        // Refuse oversized bodies before reading them when the length is announced
+        if let (Some(limit), Some(length)) = (request.max_response_bytes, response.content_length()) {
+            let length = usize::try_from(length).unwrap_or(usize::MAX);
+            if length > limit {
+                return Err(request.response_too_large(length));
+            }
+        }
+        Ok(response)
+    }
+}
+
+impl RpcTransport for ReqwestTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let mut response = self.post(&request, started).await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+
+            let mut body = Vec::new();
+            while let Some(chunk) = response.chunk().await.map_err(|e| self.map_error(e, &request, started))? {
+                body.extend_from_slice(&chunk);
+                if request.max_response_bytes.is_some_and(|limit| body.len() > limit) {
+                    return Err(request.response_too_large(body.len()));
+                }
+            }
+
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+
+    fn send_streaming<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<StreamingResponse>> {
+        Box::pin(async move {
+            let started = Instant::now();
+            let response = self.post(&request, started).await?;
+            let status = response.status().as_u16();
+            let headers = response.headers().clone();
+
+            let timeouts = self.timeouts;
+            let timeout = request.timeout;
+            let operation = format!("RPC {} request", request.method());
+            let body = stream::try_unfold(response, move |mut response| {
+                let operation = operation.clone();
+                async move {
+                    match response.chunk().await {
+                        Ok(chunk) => Ok(chunk.map(|chunk| (chunk.to_vec(), response))),
+                        Err(e) if e.is_timeout() => Err(timeouts.error(&e, timeout, started, operation)),
+                        Err(e) => Err(BlockProductionError::Http { source: e.without_url(), context: Some(operation) }),
+                    }
+                }
+            });
+
+            Ok(StreamingResponse {
+                status,
+                headers,
+                body: Box::pin(body),
+            })
+        })
+    }
+}
+
+/// In-process transport answering from canned responses, for tests without an HTTP server
+///
+/// Responses are queued per JSON-RPC method and served in order; the last one
+/// keeps being served. Unknown methods get a JSON-RPC "Method not found" error.
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<HashMap<String, VecDeque<TransportResponse>>>,
+    requests: Mutex<Vec<serde_json::Value>>,
+}
+
+impl MockTransport {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a JSON-RPC `result` for `method`
+    #[must_use]
+    pub fn respond_result(self, method: &str, result: serde_json::Value) -> Self {
+        self.respond(method, TransportResponse::json(&json!({
+            "jsonrpc": "2.0",
+            "result": result,
+            "id": 1
+        })))
+    }
+
+    /// Queue a JSON-RPC error for `method`
+    #[must_use]
+    pub fn respond_error(self, method: &str, code: i32, message: &str) -> Self {
+        self.respond(method, TransportResponse::json(&json!({
+            "jsonrpc": "2.0",
+            "error": {"code": code, "message": message},
+            "id": 1
+        })))
+    }
+
+    /// Queue a raw response for `method`
+    #[must_use]
+    pub fn respond(self, method: &str, response: TransportResponse) -> Self {
+        self.lock_responses()
+            .entry(method.to_string())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// Request bodies received so far
+    pub fn requests(&self) -> Vec<serde_json::Value> {
+        self.requests.lock().unwrap_or_else(std::sync::PoisonError::into_inner).clone()
+    }
+
+    /// Number of requests received for `method`
+    pub fn request_count(&self, method: &str) -> usize {
+        self.requests.lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .filter(|request| request.get("method").and_then(|v| v.as_str()) == Some(method))
+            .count()
+    }
+
+    fn lock_responses(&self) -> std::sync::MutexGuard<'_, HashMap<String, VecDeque<TransportResponse>>> {
+        self.responses.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl RpcTransport for MockTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>> {
+        self.requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .push(request.body.clone());
+
+        let response = {
+            let mut responses = self.lock_responses();
+            match responses.get_mut(request.method()) {
+                Some(queue) if queue.len() > 1 => queue.pop_front(),
+                Some(queue) => queue.front().cloned(),
+                None => None,
+            }
+        };
+
+        let response = response.unwrap_or_else(|| TransportResponse::json(&json!({
+            "jsonrpc": "2.0",
+            "error": {"code": -32601, "message": "Method not found"},
+            "id": 1
+        })));
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+/// Offline transport reading responses from `<directory>/<method>.json`
+///
+/// A file may hold a full JSON-RPC response (with `result` or `error`) or just
+/// the `result` value.
+#[derive(Debug, Clone)]
+pub struct FileTransport {
+    directory: PathBuf,
+}
+
+impl FileTransport {
+    #[must_use]
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+}
+
+impl RpcTransport for FileTransport {
+    fn send<'a>(&'a self, request: TransportRequest<'a>) -> BoxFuture<'a, Result<TransportResponse>> {
+        Box::pin(async move {
+            let path = self.directory.join(format!("{}.json", request.method()));
+            let contents = tokio::fs::read(&path).await.map_err(|e| BlockProductionError::config_error(
+                &format!("No response file for {} at {}: {e}", request.method(), path.display()),
+                Some("transport"),
+                Some("Save the JSON-RPC response as <method>.json in the transport directory"),
+            ))?;
+
+            let value: serde_json::Value = serde_json::from_slice(&contents)?;
+            let is_rpc_response = value.get("result").is_some() || value.get("error").is_some();
+            if is_rpc_response {
+                return Ok(TransportResponse::new(200, contents));
+            }
+            Ok(TransportResponse::json(&json!({
+                "jsonrpc": "2.0",
+                "result": value,
+                "id": 1
+            })))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_for<'a>(body: &'a serde_json::Value, headers: &'a HeaderMap) -> TransportRequest<'a> {
+        TransportRequest {
+            endpoint: "http://localhost",
+            headers,
+            body,
+            timeout: Duration::from_secs(1),
+            max_response_bytes: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport_queues_responses() {
+        let transport = MockTransport::new()
+            .respond_error("getSlot", -32004, "not yet")
+            .respond_result("getSlot", json!(42));
+        let headers = HeaderMap::new();
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot"});
+
+        let decode = |response: TransportResponse| serde_json::from_slice::<serde_json::Value>(&response.body).unwrap();
+        let first = decode(transport.send(request_for(&body, &headers)).await.unwrap());
+        let second = decode(transport.send(request_for(&body, &headers)).await.unwrap());
+        let third = decode(transport.send(request_for(&body, &headers)).await.unwrap());
+
+        assert_eq!(first["error"]["code"], -32004);
+        assert_eq!(second["result"], 42);
+        assert_eq!(third["result"], 42);
+        assert_eq!(transport.request_count("getSlot"), 3);
+
+        let unknown = json!({"jsonrpc": "2.0", "id": 1, "method": "getHealth"});
+        let response = decode(transport.send(request_for(&unknown, &headers)).await.unwrap());
+        assert_eq!(response["error"]["code"], -32601);
+    }
+
+    #[tokio::test]
+    async fn test_file_transport() {
+        let directory = std::env::temp_dir().join(format!("bp-file-transport-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(directory.join("getSlot.json"), "1234").unwrap();
+        std::fs::write(directory.join("getHealth.json"), r#"{"jsonrpc":"2.0","result":"ok","id":1}"#).unwrap();
+
+        let transport = FileTransport::new(&directory);
+        let headers = HeaderMap::new();
+
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot"});
+        let response = transport.send(request_for(&body, &headers)).await.unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(value["result"], 1234);
+
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": "getHealth"});
+        let response = transport.send(request_for(&body, &headers)).await.unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(value["result"], "ok");
+
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": "getVersion"});
+        let error = transport.send(request_for(&body, &headers)).await.unwrap_err();
+        assert!(matches!(error, BlockProductionError::Config { .. }));
+
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
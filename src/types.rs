@@ -25,7 +25,7 @@ pub struct BlockProductionValue {
 }
 
 /// Slot range information
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub struct SlotRange {
     pub first_slot: u64,
@@ -38,6 +38,187 @@ impl SlotRange {
     pub const fn slot_count(&self) -> u64 {
         self.last_slot.saturating_sub(self.first_slot)
     }
+
+    /// Split the (inclusive) range into consecutive chunks of at most `chunk_size` slots
+    #[must_use]
+    pub fn chunks(&self, chunk_size: u64) -> Vec<SlotRange> {
+        let chunk_size = chunk_size.max(1);
+        let mut chunks = Vec::new();
+        let mut first_slot = self.first_slot;
+        loop {
+            let last_slot = first_slot.saturating_add(chunk_size - 1).min(self.last_slot);
+            chunks.push(SlotRange { first_slot, last_slot });
+            if last_slot >= self.last_slot {
+                break;
+            }
+            first_slot = last_slot + 1;
+        }
+        chunks
+    }
+}
+
+/// Minimum number of slots in an epoch during warmup (mirrors the Solana runtime constant)
+pub const MINIMUM_SLOTS_PER_EPOCH: u64 = 32;
+
+/// Current epoch information returned by getEpochInfo
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochInfo {
+    /// Current slot
+    pub absolute_slot: u64,
+    /// Current block height
+    pub block_height: u64,
+    /// Current epoch
+    pub epoch: u64,
+    /// Current slot relative to the start of the current epoch
+    pub slot_index: u64,
+    /// Number of slots in this epoch
+    pub slots_in_epoch: u64,
+    /// Total number of transactions processed without error since genesis
+    pub transaction_count: Option<u64>,
+}
+
+impl EpochInfo {
+    /// First slot of the current epoch
+    #[must_use]
+    pub const fn first_slot(&self) -> u64 {
+        self.absolute_slot.saturating_sub(self.slot_index)
+    }
+
+    /// Last slot of the current epoch
+    #[must_use]
+    pub const fn last_slot(&self) -> u64 {
+        self.first_slot().saturating_add(self.slots_in_epoch).saturating_sub(1)
+    }
+
+    /// Progress context for the current epoch
+    #[must_use]
+    pub fn epoch_context(&self) -> EpochContext {
+        EpochContext::new(self.epoch, self.first_slot(), self.slots_in_epoch, self.absolute_slot)
+    }
+}
+
+/// Cluster epoch schedule returned by getEpochSchedule
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochSchedule {
+    /// Maximum number of slots in each epoch
+    pub slots_per_epoch: u64,
+    /// Number of slots before beginning of an epoch to calculate a leader schedule for that epoch
+    pub leader_schedule_slot_offset: u64,
+    /// Whether epochs start short and grow
+    pub warmup: bool,
+    /// First normal-length epoch
+    pub first_normal_epoch: u64,
+    /// First slot of the first normal-length epoch
+    pub first_normal_slot: u64,
+}
+
+impl EpochSchedule {
+    /// Number of slots in the given epoch, accounting for warmup epochs
+    #[must_use]
+    pub fn slots_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch < self.first_normal_epoch {
+            #[allow(clippy::cast_possible_truncation)]
+            let exponent = (epoch as u32).saturating_add(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros());
+            2u64.saturating_pow(exponent)
+        } else {
+            self.slots_per_epoch
+        }
+    }
+
+    /// First slot of the given epoch
+    #[must_use]
+    pub fn first_slot_in_epoch(&self, epoch: u64) -> u64 {
+        if epoch <= self.first_normal_epoch {
+            #[allow(clippy::cast_possible_truncation)]
+            let warmup_epochs = 2u64.saturating_pow(epoch as u32).saturating_sub(1);
+            warmup_epochs.saturating_mul(MINIMUM_SLOTS_PER_EPOCH)
+        } else {
+            epoch
+                .saturating_sub(self.first_normal_epoch)
+                .saturating_mul(self.slots_per_epoch)
+                .saturating_add(self.first_normal_slot)
+        }
+    }
+
+    /// Last slot of the given epoch
+    #[must_use]
+    pub fn last_slot_in_epoch(&self, epoch: u64) -> u64 {
+        self.first_slot_in_epoch(epoch)
+            .saturating_add(self.slots_in_epoch(epoch))
+            .saturating_sub(1)
+    }
+
+    /// Epoch containing the given slot
+    #[must_use]
+    pub fn epoch_for_slot(&self, slot: u64) -> u64 {
+        if slot < self.first_normal_slot {
+            let epoch = slot
+                .saturating_add(MINIMUM_SLOTS_PER_EPOCH)
+                .saturating_add(1)
+                .next_power_of_two()
+                .trailing_zeros()
+                .saturating_sub(MINIMUM_SLOTS_PER_EPOCH.trailing_zeros())
+                .saturating_sub(1);
+            u64::from(epoch)
+        } else {
+            (slot - self.first_normal_slot)
+                .checked_div(self.slots_per_epoch)
+                .unwrap_or(0)
+                .saturating_add(self.first_normal_epoch)
+        }
+    }
+}
+
+/// Epoch context attached to data fetched through the epoch API
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EpochContext {
+    /// Epoch number
+    pub epoch: u64,
+    /// First slot of the epoch
+    pub first_slot: u64,
+    /// Last slot of the epoch (may be in the future for the current epoch)
+    pub last_slot: u64,
+    /// Total number of slots in the epoch
+    pub slots_in_epoch: u64,
+    /// Slots elapsed within the epoch at fetch time
+    pub slots_elapsed: u64,
+    /// Slots remaining until the epoch boundary
+    pub slots_remaining: u64,
+    /// Epoch progress as percentage (0.0 to 100.0)
+    pub progress_percent: f64,
+    /// Whether the epoch has finished
+    pub is_complete: bool,
+}
+
+impl EpochContext {
+    /// Build epoch context from the epoch boundaries and the last slot observed
+    #[must_use]
+    pub fn new(epoch: u64, first_slot: u64, slots_in_epoch: u64, observed_slot: u64) -> Self {
+        let slots_elapsed = observed_slot
+            .saturating_sub(first_slot)
+            .saturating_add(1)
+            .min(slots_in_epoch);
+        let slots_remaining = slots_in_epoch.saturating_sub(slots_elapsed);
+        #[allow(clippy::cast_precision_loss)]
+        let progress_percent = if slots_in_epoch > 0 {
+            (slots_elapsed as f64 / slots_in_epoch as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        Self {
+            epoch,
+            first_slot,
+            last_slot: first_slot.saturating_add(slots_in_epoch).saturating_sub(1),
+            slots_in_epoch,
+            slots_elapsed,
+            slots_remaining,
+            progress_percent,
+            is_complete: slots_remaining == 0,
+        }
+    }
 }
 
 /// Individual validator skip rate data
@@ -53,6 +234,82 @@ pub struct ValidatorSkipRate {
     pub missed_slots: u64,
     /// Skip rate as percentage (0.0 to 100.0)
     pub skip_rate_percent: f64,
+    /// Exact leader slots that were skipped (empty unless requested via `include_skipped_slots`)
+    #[serde(default)]
+    pub skipped_slots: Vec<u64>,
+    /// Stake and vote account data (only set when requested via `include_stake`)
+    pub stake_info: Option<ValidatorStakeInfo>,
+    /// On-chain validator-info metadata (only set when requested via `include_validator_info`)
+    pub validator_info: Option<ValidatorInfo>,
+}
+
+/// Config program id that owns validator-info accounts
+pub const CONFIG_PROGRAM_ID: &str = "Config1111111111111111111111111111111111111";
+
+/// Validator identity metadata published on-chain via validator-info accounts
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
+pub struct ValidatorInfo {
+    /// Validator display name
+    pub name: Option<String>,
+    /// Validator website
+    pub website: Option<String>,
+    /// Keybase username
+    pub keybase_username: Option<String>,
+    /// Free-form details
+    pub details: Option<String>,
+    /// Icon URL
+    pub icon_url: Option<String>,
+}
+
+impl ValidatorInfo {
+    /// Decode a jsonParsed Config program account into (identity, info)
+    ///
+    /// Returns `None` for config accounts that are not validator-info records.
+    #[must_use]
+    pub fn from_parsed_account(account: &serde_json::Value) -> Option<(String, Self)> {
+        let parsed = account.pointer("/account/data/parsed")?;
+        if parsed.get("type").and_then(serde_json::Value::as_str) != Some("validatorInfo") {
+            return None;
+        }
+
+        let info = parsed.get("info")?;
+        // keys[0] is the validator-info marker key, the signing key is the validator identity
+        let identity = info.get("keys")?.as_array()?.iter()
+            .find(|key| key.get("signer").and_then(serde_json::Value::as_bool) == Some(true))?
+            .get("pubkey")?
+            .as_str()?
+            .to_string();
+
+        let config_data = info.get("configData")?;
+        let field = |name: &str| {
+            config_data.get(name)
+                .and_then(serde_json::Value::as_str)
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .map(String::from)
+        };
+
+        Some((identity, Self {
+            name: field("name"),
+            website: field("website"),
+            keybase_username: field("keybaseUsername"),
+            details: field("details"),
+            icon_url: field("iconUrl"),
+        }))
+    }
+}
+
+/// Vote account data joined onto a validator from getVoteAccounts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorStakeInfo {
+    /// Vote account public key
+    pub vote_pubkey: String,
+    /// Activated stake in lamports
+    pub activated_stake: u64,
+    /// Commission percentage (0-100)
+    pub commission: u8,
+    /// Whether the vote account is delinquent
+    pub delinquent: bool,
 }
 
 impl ValidatorSkipRate {
@@ -73,9 +330,20 @@ impl ValidatorSkipRate {
             blocks_produced,
             missed_slots,
             skip_rate_percent,
+            skipped_slots: Vec::new(),
+            stake_info: None,
+            validator_info: None,
         }
     }
 
+    /// Validator name from on-chain metadata, falling back to the pubkey
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.validator_info.as_ref()
+            .and_then(|info| info.name.as_deref())
+            .unwrap_or(&self.pubkey)
+    }
+
     /// Check if validator has perfect performance (0% skip rate)
     #[must_use] 
     pub fn is_perfect(&self) -> bool {
@@ -179,6 +447,137 @@ pub struct SkipRateStatistics {
     pub network_efficiency_percent: f64,
     /// Significance-weighted network efficiency (excluding noise from tiny validators)
     pub weighted_network_efficiency_percent: f64,
+    /// Stake-weighted metrics (only set when stake data was joined via `include_stake`)
+    pub stake_weighted: Option<StakeWeightedStatistics>,
+}
+
+/// Skip rate metrics weighted by activated stake
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StakeWeightedStatistics {
+    /// Number of validators with leader slots and known stake
+    pub validators_with_stake: usize,
+    /// Total activated stake of those validators (lamports)
+    pub total_activated_stake: u64,
+    /// Skip rate weighted by activated stake
+    pub stake_weighted_skip_rate_percent: f64,
+    /// Skip rate below which half of the stake sits
+    pub stake_weighted_median_skip_rate_percent: f64,
+    /// Skip rate below which 90% of the stake sits
+    pub stake_weighted_skip_rate_90th_percentile: f64,
+    /// Skip rate below which 95% of the stake sits
+    pub stake_weighted_skip_rate_95th_percentile: f64,
+    /// Percentage of stake held by validators with concerning skip rate (> 5%)
+    pub concerning_stake_percent: f64,
+    /// Percentage of stake held by completely offline validators (100% skip rate)
+    pub offline_stake_percent: f64,
+    /// Percentage of stake held by delinquent vote accounts
+    pub delinquent_stake_percent: f64,
+}
+
+/// Vote account entry returned by getVoteAccounts
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcVoteAccount {
+    /// Vote account public key
+    pub vote_pubkey: String,
+    /// Validator identity public key
+    pub node_pubkey: String,
+    /// Activated stake in lamports
+    pub activated_stake: u64,
+    /// Commission percentage (0-100)
+    pub commission: u8,
+    /// Whether the vote account is staked for this epoch
+    pub epoch_vote_account: bool,
+    /// Most recent slot voted on
+    pub last_vote: u64,
+    /// Current root slot
+    pub root_slot: u64,
+}
+
+/// Vote accounts returned by getVoteAccounts, split by delinquency
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct VoteAccounts {
+    /// Vote accounts that are voting
+    pub current: Vec<RpcVoteAccount>,
+    /// Vote accounts that are behind
+    pub delinquent: Vec<RpcVoteAccount>,
+}
+
+impl VoteAccounts {
+    /// Build stake info keyed by validator identity
+    ///
+    /// When an identity has several vote accounts the one with the most stake wins.
+    #[must_use]
+    pub fn stake_by_identity(&self) -> HashMap<String, ValidatorStakeInfo> {
+        let mut stake_by_identity: HashMap<String, ValidatorStakeInfo> = HashMap::new();
+        let accounts = self.current.iter().map(|account| (account, false))
+            .chain(self.delinquent.iter().map(|account| (account, true)));
+
+        for (account, delinquent) in accounts {
+            let replace = stake_by_identity
+                .get(&account.node_pubkey)
+                .map_or(true, |existing| account.activated_stake > existing.activated_stake);
+            if replace {
+                stake_by_identity.insert(account.node_pubkey.clone(), ValidatorStakeInfo {
+                    vote_pubkey: account.vote_pubkey.clone(),
+                    activated_stake: account.activated_stake,
+                    commission: account.commission,
+                    delinquent,
+                });
+            }
+        }
+
+        stake_by_identity
+    }
+}
+
+/// Gossip contact info for a cluster node returned by getClusterNodes
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterNode {
+    /// Node identity public key
+    pub pubkey: String,
+    /// Gossip network address
+    pub gossip: Option<String>,
+    /// TPU network address
+    pub tpu: Option<String>,
+    /// JSON RPC network address, if the RPC service is enabled
+    pub rpc: Option<String>,
+    /// Software version of the node
+    pub version: Option<String>,
+    /// Unique identifier of the node's feature set
+    pub feature_set: Option<u32>,
+    /// Shred version the node is configured to use
+    pub shred_version: Option<u16>,
+}
+
+/// Skip rate statistics for validators running one software version
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct VersionSkipRateStatistics {
+    /// Software version reported in gossip ("unknown" if the node is not in gossip)
+    pub version: String,
+    /// Feature set identifier reported in gossip
+    pub feature_set: Option<u32>,
+    /// Number of validators running this version
+    pub validator_count: usize,
+    /// Number of significant validators (>= 50 slots) running this version
+    pub significant_validators: usize,
+    /// Total leader slots of these validators
+    pub total_leader_slots: u64,
+    /// Total missed slots of these validators
+    pub total_missed_slots: u64,
+    /// Share of all leader slots assigned to this version
+    pub leader_slot_share_percent: f64,
+    /// Skip rate across all slots of this version
+    pub overall_skip_rate_percent: f64,
+    /// Significance-weighted skip rate (same weighting as `SkipRateStatistics`)
+    pub weighted_skip_rate_percent: f64,
+    /// Median skip rate
+    pub median_skip_rate_percent: f64,
+    /// Skip rate at 90th percentile
+    pub skip_rate_90th_percentile: f64,
+    /// Skip rate at 95th percentile
+    pub skip_rate_95th_percentile: f64,
 }
 
 /// Distribution data for plotting histograms and percentile charts
@@ -248,6 +647,8 @@ pub struct ValidatorPerformanceSnapshot {
     pub blocks_produced: u64,
     /// Performance category
     pub performance_category: ValidatorPerformanceCategory,
+    /// On-chain validator-info metadata (only set when requested via `include_validator_info`)
+    pub validator_info: Option<ValidatorInfo>,
 }
 
 /// Categories for easy frontend filtering and color coding
@@ -442,7 +843,7 @@ impl std::fmt::Display for AlertCategory {
 }
 
 /// Complete block production data for production use
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct BlockProductionData {
     /// List of validator skip rates
     pub validators: Vec<ValidatorSkipRate>,
@@ -458,6 +859,367 @@ pub struct BlockProductionData {
     pub slot_range: SlotRange,
     /// When the data was fetched
     pub fetched_at: DateTime<Utc>,
+    /// Epoch context (only set when fetched through the epoch API)
+    pub epoch_context: Option<EpochContext>,
+    /// Skip rate statistics per software version (only set when requested via `include_version_breakdown`)
+    pub version_statistics: Option<Vec<VersionSkipRateStatistics>>,
+    /// Chunk coverage (only set when a range fetch was split into chunks)
+    pub range_coverage: Option<RangeCoverage>,
+    /// Telemetry of the getBlockProduction call(s) that produced this data
+    /// (None when served from the response cache)
+    pub telemetry: Option<RequestTelemetry>,
+}
+
+impl BlockProductionData {
+    /// Attach validator-info metadata to validators and performance snapshots
+    pub fn apply_validator_info(&mut self, validator_info: &HashMap<String, ValidatorInfo>) {
+        for validator in &mut self.validators {
+            validator.validator_info = validator_info.get(&validator.pubkey).cloned();
+        }
+        for snapshot in &mut self.performance_snapshots {
+            snapshot.validator_info = validator_info.get(&snapshot.validator_pubkey).cloned();
+        }
+    }
+}
+
+/// A chunk of a range fetch that could not be fetched
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct FailedRangeChunk {
+    /// Slot range of the chunk
+    pub range: SlotRange,
+    /// Error returned for the chunk
+    pub error: String,
+}
+
+/// Coverage of a chunked range fetch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RangeCoverage {
+    /// Slot range that was requested
+    pub requested_range: SlotRange,
+    /// Number of chunks the range was split into
+    pub chunk_count: usize,
+    /// Chunks that failed and are missing from the data
+    pub failed_chunks: Vec<FailedRangeChunk>,
+    /// Number of slots covered by successful chunks
+    pub covered_slots: u64,
+    /// Percentage of the requested slots covered by successful chunks
+    pub coverage_percent: f64,
+}
+
+impl RangeCoverage {
+    /// Whether some chunks are missing from the data
+    #[must_use]
+    pub fn is_partial(&self) -> bool {
+        !self.failed_chunks.is_empty()
+    }
+}
+
+/// Block production for one validator in one epoch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorEpochSkipRate {
+    /// Epoch number
+    pub epoch: u64,
+    /// Number of leader slots assigned
+    pub leader_slots: u64,
+    /// Number of blocks actually produced
+    pub blocks_produced: u64,
+    /// Number of slots missed
+    pub missed_slots: u64,
+    /// Skip rate as percentage
+    pub skip_rate_percent: f64,
+}
+
+/// Skip rate history of a single validator across several epochs
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorEpochHistory {
+    /// Validator identity public key
+    pub pubkey: String,
+    /// Per-epoch skip rates, oldest epoch first (epochs without leader slots are omitted)
+    pub epochs: Vec<ValidatorEpochSkipRate>,
+    /// Leader slots across all fetched epochs
+    pub total_leader_slots: u64,
+    /// Missed slots across all fetched epochs
+    pub total_missed_slots: u64,
+    /// Skip rate across all fetched epochs
+    pub overall_skip_rate_percent: f64,
+}
+
+/// An epoch that could not be fetched as part of an epoch history
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct UnavailableEpoch {
+    /// Epoch number
+    pub epoch: u64,
+    /// Error returned by the node
+    pub error: String,
+}
+
+/// Block production over several consecutive epochs
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EpochHistory {
+    /// Per-epoch block production data, oldest epoch first
+    pub epochs: Vec<BlockProductionData>,
+    /// Per-validator skip rate per epoch, most leader slots first
+    pub validator_history: Vec<ValidatorEpochHistory>,
+    /// Epochs the node could not serve
+    pub unavailable_epochs: Vec<UnavailableEpoch>,
+}
+
+impl EpochHistory {
+    /// Build the history and the per-validator view from per-epoch data
+    #[allow(clippy::cast_precision_loss)]
+    pub fn new(mut epochs: Vec<BlockProductionData>, mut unavailable_epochs: Vec<UnavailableEpoch>) -> Self {
+        let epoch_of = |data: &BlockProductionData| data.epoch_context.as_ref().map_or(0, |context| context.epoch);
+        epochs.sort_by_key(|data| epoch_of(data));
+        unavailable_epochs.sort_by_key(|unavailable| unavailable.epoch);
+
+        let mut by_validator: HashMap<String, Vec<ValidatorEpochSkipRate>> = HashMap::new();
+        for data in &epochs {
+            let epoch = epoch_of(data);
+            for validator in &data.validators {
+                by_validator.entry(validator.pubkey.clone()).or_default().push(ValidatorEpochSkipRate {
+                    epoch,
+                    leader_slots: validator.leader_slots,
+                    blocks_produced: validator.blocks_produced,
+                    missed_slots: validator.missed_slots,
+                    skip_rate_percent: validator.skip_rate_percent,
+                });
+            }
+        }
+
+        let mut validator_history: Vec<ValidatorEpochHistory> = by_validator
+            .into_iter()
+            .map(|(pubkey, epochs)| {
+                let total_leader_slots: u64 = epochs.iter().map(|e| e.leader_slots).sum();
+                let total_missed_slots: u64 = epochs.iter().map(|e| e.missed_slots).sum();
+                let overall_skip_rate_percent = if total_leader_slots > 0 {
+                    (total_missed_slots as f64 / total_leader_slots as f64) * 100.0
+                } else {
+                    0.0
+                };
+                ValidatorEpochHistory {
+                    pubkey,
+                    epochs,
+                    total_leader_slots,
+                    total_missed_slots,
+                    overall_skip_rate_percent,
+                }
+            })
+            .collect();
+
+        validator_history.sort_by(|a, b| {
+            b.total_leader_slots.cmp(&a.total_leader_slots).then_with(|| a.pubkey.cmp(&b.pubkey))
+        });
+
+        Self { epochs, validator_history, unavailable_epochs }
+    }
+
+    /// Get the history of one validator by identity
+    pub fn validator(&self, pubkey: &str) -> Option<&ValidatorEpochHistory> {
+        self.validator_history.iter().find(|history| history.pubkey == pubkey)
+    }
+}
+
+/// Projected end-of-epoch skip rate for one validator
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorSkipRateProjection {
+    /// Validator identity public key
+    pub pubkey: String,
+    /// Leader slots observed so far
+    pub current_leader_slots: u64,
+    /// Missed slots observed so far
+    pub current_missed_slots: u64,
+    /// Skip rate observed so far
+    pub current_skip_rate_percent: f64,
+    /// Leader slots still scheduled in the epoch
+    pub remaining_leader_slots: u64,
+    /// Expected missed slots at epoch end
+    pub projected_missed_slots: f64,
+    /// Projected skip rate at epoch end
+    pub projected_skip_rate_percent: f64,
+    /// Lower bound of the 95% projection band
+    pub projected_skip_rate_low_percent: f64,
+    /// Upper bound of the 95% projection band
+    pub projected_skip_rate_high_percent: f64,
+}
+
+/// Projected end-of-epoch skip rate for the whole cluster
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ClusterSkipRateProjection {
+    /// Overall skip rate observed so far
+    pub current_overall_skip_rate_percent: f64,
+    /// Leader slots still scheduled in the epoch
+    pub remaining_leader_slots: u64,
+    /// Projected overall skip rate at epoch end
+    pub projected_overall_skip_rate_percent: f64,
+    /// Lower bound of the 95% projection band
+    pub projected_overall_skip_rate_low_percent: f64,
+    /// Upper bound of the 95% projection band
+    pub projected_overall_skip_rate_high_percent: f64,
+}
+
+/// End-of-epoch skip rate projection built from partial epoch data
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EpochProjection {
+    /// Epoch being projected
+    pub epoch_context: EpochContext,
+    /// Last slot included in the observed data
+    pub observed_slot: u64,
+    /// Per-validator projections, highest projected skip rate first
+    pub validators: Vec<ValidatorSkipRateProjection>,
+    /// Cluster-level projection
+    pub cluster: ClusterSkipRateProjection,
+}
+
+impl EpochProjection {
+    /// Get the projection of one validator by identity
+    pub fn validator(&self, pubkey: &str) -> Option<&ValidatorSkipRateProjection> {
+        self.validators.iter().find(|projection| projection.pubkey == pubkey)
+    }
+}
+
+/// Version information returned by getVersion
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RpcVersionInfo {
+    /// Software version of solana-core
+    #[serde(rename = "solana-core")]
+    pub solana_core: String,
+    /// Unique identifier of the current software's feature set
+    #[serde(rename = "feature-set")]
+    pub feature_set: Option<u32>,
+}
+
+/// Solana cluster identified by its genesis hash
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SolanaCluster {
+    MainnetBeta,
+    Testnet,
+    Devnet,
+    Unknown,
+}
+
+impl SolanaCluster {
+    /// Genesis hash of mainnet-beta
+    pub const MAINNET_BETA_GENESIS_HASH: &'static str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+    /// Genesis hash of testnet
+    pub const TESTNET_GENESIS_HASH: &'static str = "4uhcVJyU9pJkvQyS88uRDiswHXSCkY3zQawwpjk2NsNY";
+    /// Genesis hash of devnet
+    pub const DEVNET_GENESIS_HASH: &'static str = "EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG";
+
+    /// Identify the cluster from its genesis hash
+    #[must_use]
+    pub fn from_genesis_hash(genesis_hash: &str) -> Self {
+        match genesis_hash {
+            Self::MAINNET_BETA_GENESIS_HASH => Self::MainnetBeta,
+            Self::TESTNET_GENESIS_HASH => Self::Testnet,
+            Self::DEVNET_GENESIS_HASH => Self::Devnet,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for SolanaCluster {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MainnetBeta => write!(f, "mainnet-beta"),
+            Self::Testnet => write!(f, "testnet"),
+            Self::Devnet => write!(f, "devnet"),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Slots covered by the SlotHistory sysvar, which bounds getBlockProduction ranges
+pub const SLOT_HISTORY_MAX_ENTRIES: u64 = 1_048_576;
+
+/// Outcome of a single RPC call made while diagnosing an endpoint
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpcCallDiagnostic {
+    /// RPC method name
+    pub method: String,
+    /// Round-trip latency in milliseconds
+    pub latency_ms: u64,
+    /// Error message if the call failed
+    pub error: Option<String>,
+}
+
+impl RpcCallDiagnostic {
+    /// Whether the call succeeded
+    #[must_use]
+    pub const fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Health, version and ledger coverage of an RPC endpoint
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EndpointDiagnostics {
+    /// Endpoint that was diagnosed
+    pub rpc_endpoint: String,
+    /// Whether getHealth reported "ok"
+    pub healthy: bool,
+    /// solana-core version reported by getVersion
+    pub version: Option<String>,
+    /// Feature set reported by getVersion
+    pub feature_set: Option<u32>,
+    /// Genesis hash reported by getGenesisHash
+    pub genesis_hash: Option<String>,
+    /// Cluster detected from the genesis hash
+    pub cluster: SolanaCluster,
+    /// Current slot reported by getSlot
+    pub slot: Option<u64>,
+    /// Lowest slot the node has in its ledger (minimumLedgerSlot)
+    pub minimum_ledger_slot: Option<u64>,
+    /// Lowest confirmed block the node can serve (getFirstAvailableBlock)
+    pub first_available_block: Option<u64>,
+    /// Reference endpoint used to measure slot lag
+    pub reference_endpoint: Option<String>,
+    /// Current slot reported by the reference endpoint
+    pub reference_slot: Option<u64>,
+    /// Slots this endpoint is behind the reference (negative if ahead)
+    pub slot_lag: Option<i64>,
+    /// Per-call outcome and latency, in call order
+    pub calls: Vec<RpcCallDiagnostic>,
+    /// Outcome and latency of the reference endpoint's getSlot call
+    pub reference_call: Option<RpcCallDiagnostic>,
+    /// When the diagnostics were collected
+    pub checked_at: DateTime<Utc>,
+}
+
+impl EndpointDiagnostics {
+    /// Slot range the endpoint can serve block production for, if known
+    ///
+    /// Starts at the node's first available ledger slot, but no earlier than the
+    /// SlotHistory window that getBlockProduction accepts (`SLOT_HISTORY_MAX_ENTRIES`
+    /// slots back from the current slot).
+    #[must_use]
+    pub fn servable_range(&self) -> Option<SlotRange> {
+        let last_slot = self.slot?;
+        let ledger_start = match (self.minimum_ledger_slot, self.first_available_block) {
+            (Some(ledger), Some(block)) => ledger.max(block),
+            (Some(slot), None) | (None, Some(slot)) => slot,
+            (None, None) => return None,
+        };
+        let first_slot = ledger_start.max(last_slot.saturating_sub(SLOT_HISTORY_MAX_ENTRIES - 1));
+        (first_slot <= last_slot).then_some(SlotRange { first_slot, last_slot })
+    }
+
+    /// Whether the endpoint can serve the given slot range
+    #[must_use]
+    pub fn can_serve(&self, range: &SlotRange) -> bool {
+        self.servable_range()
+            .is_some_and(|servable| servable.first_slot <= range.first_slot && range.last_slot <= servable.last_slot)
+    }
+
+    /// Average latency of the successful calls in milliseconds
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn average_latency_ms(&self) -> Option<f64> {
+        let latencies: Vec<u64> = self.calls.iter().filter(|c| c.succeeded()).map(|c| c.latency_ms).collect();
+        if latencies.is_empty() {
+            return None;
+        }
+        Some(latencies.iter().sum::<u64>() as f64 / latencies.len() as f64)
+    }
 }
 
 /// Debug version with additional raw data
@@ -473,10 +1235,140 @@ pub struct BlockProductionDataDebug {
     pub response_metadata: ResponseMetadata,
 }
 
+/// What one provider returned in a consensus fetch
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProviderResponse {
+    /// Endpoint URL
+    pub endpoint: String,
+    /// Slot range the provider reported
+    pub range: Option<SlotRange>,
+    /// Number of validators in the provider's response
+    pub validator_count: usize,
+    /// Total leader slots in the provider's response
+    pub total_leader_slots: u64,
+    /// Total blocks produced in the provider's response
+    pub total_blocks_produced: u64,
+    /// Error message if the provider failed
+    pub error: Option<String>,
+}
+
+/// Counts reported for a validator by one provider
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ProviderCounts {
+    /// Endpoint URL
+    pub endpoint: String,
+    /// (`leader_slots`, `blocks_produced`), or None if the validator was missing
+    pub counts: Option<(u64, u64)>,
+}
+
+/// A validator whose counts differ between providers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ValidatorDisagreement {
+    /// Validator identity public key
+    pub pubkey: String,
+    /// Counts per responding provider
+    pub counts: Vec<ProviderCounts>,
+    /// Whether a majority of providers agreed on the counts
+    pub reconciled: bool,
+}
+
+/// Comparison of the same getBlockProduction request across providers
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ConsensusReport {
+    /// Slot range requested from every provider
+    pub requested_range: SlotRange,
+    /// Per-provider outcome
+    pub providers: Vec<ProviderResponse>,
+    /// Whether every responding provider reported the same range
+    pub ranges_match: bool,
+    /// Validators reported identically by every responding provider
+    pub agreeing_validators: usize,
+    /// Validators whose counts differ between providers
+    pub disagreements: Vec<ValidatorDisagreement>,
+}
+
+impl ConsensusReport {
+    /// Whether every responding provider returned identical data
+    #[must_use]
+    pub fn is_unanimous(&self) -> bool {
+        self.ranges_match && self.disagreements.is_empty()
+    }
+}
+
+/// Result of a consensus fetch
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ConsensusResult {
+    /// Providers agreed (or a majority agreed on every validator and the range)
+    Reconciled {
+        /// Data built from the agreed counts
+        data: Box<BlockProductionData>,
+        /// Comparison details, including any outvoted providers
+        report: ConsensusReport,
+    },
+    /// Providers disagreed without a majority, or too few providers answered
+    Unresolved(ConsensusReport),
+}
+
+impl ConsensusResult {
+    /// Comparison details
+    #[must_use]
+    pub const fn report(&self) -> &ConsensusReport {
+        match self {
+            Self::Reconciled { report, .. } | Self::Unresolved(report) => report,
+        }
+    }
+
+    /// Reconciled data, if the providers reached consensus
+    #[must_use]
+    pub fn data(&self) -> Option<&BlockProductionData> {
+        match self {
+            Self::Reconciled { data, .. } => Some(data.as_ref()),
+            Self::Unresolved(_) => None,
+        }
+    }
+}
+
+/// Health record of one endpoint in the client's endpoint pool
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EndpointHealth {
+    /// Endpoint URL
+    pub endpoint: String,
+    /// Whether this is the primary endpoint
+    pub is_primary: bool,
+    /// Whether the endpoint is currently tried in priority order
+    pub healthy: bool,
+    /// Requests answered by this endpoint
+    pub successes: u64,
+    /// Requests that failed over away from this endpoint
+    pub failures: u64,
+    /// Failures since the last success
+    pub consecutive_failures: u32,
+    /// Success rate as percentage (None before the first request)
+    pub success_rate_percent: Option<f64>,
+    /// Moving average of request latency in milliseconds
+    pub average_latency_ms: Option<f64>,
+    /// Circuit breaker state (always closed when the breaker is disabled)
+    pub circuit_state: CircuitState,
+    /// Time until an open circuit lets a probe request through
+    pub circuit_retry_in_ms: Option<u64>,
+}
+
+/// State of an endpoint's circuit breaker
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CircuitState {
+    /// Requests flow normally
+    #[default]
+    Closed,
+    /// Requests are rejected without calling the endpoint
+    Open,
+    /// A single probe request is testing whether the endpoint recovered
+    HalfOpen,
+}
+
 /// Metadata about the RPC response
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResponseMetadata {
-    /// RPC endpoint used
+    /// RPC endpoint that answered the request (a fallback if the primary failed)
     pub rpc_endpoint: String,
     /// Response time in milliseconds
     pub response_time_ms: u64,
@@ -484,16 +1376,130 @@ pub struct ResponseMetadata {
     pub retry_attempts: u32,
     /// Whether rate limiting was applied
     pub rate_limited: bool,
+    /// Whether the data was served from the response cache (raw RPC data is not kept for cached responses)
+    pub cache_hit: bool,
+    /// Age of the cached response in milliseconds
+    pub cache_age_ms: Option<u64>,
+    /// Per-attempt telemetry of the RPC call (None for cache hits)
+    pub telemetry: Option<RequestTelemetry>,
 }
 
-/// Request parameters for getBlockProduction
+/// One HTTP attempt of an RPC request
 #[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RpcAttempt {
+    /// Endpoint the attempt was sent to
+    pub endpoint: String,
+    /// Attempt number against this endpoint (1-based)
+    pub attempt: u32,
+    /// Time from sending the request to having the decoded response or error
+    pub duration_ms: u64,
+    /// HTTP status code (None if no response was received)
+    pub http_status: Option<u16>,
+    /// Size of the response body in bytes
+    pub response_bytes: Option<u64>,
+    /// Error of a failed attempt
+    pub error: Option<String>,
+    /// Backoff slept after this attempt before retrying
+    pub backoff_ms: Option<u64>,
+}
+
+impl RpcAttempt {
+    /// Whether the attempt got a usable response
+    #[must_use]
+    pub const fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Telemetry of one logical RPC request across retries and failover
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct RequestTelemetry {
+    /// JSON-RPC method
+    pub method: String,
+    /// Every HTTP attempt in the order it was made
+    pub attempts: Vec<RpcAttempt>,
+    /// Time spent waiting on the client-side rate limiter
+    pub rate_limit_wait_ms: u64,
+    /// Whether the rate limiter delayed the request
+    pub rate_limited: bool,
+    /// Lowest rate enforced by the applicable limiters (requests per second)
+    pub effective_rate: Option<f64>,
+    /// HTTP status of the last attempt
+    pub http_status: Option<u16>,
+    /// Size of the successful response body in bytes
+    pub response_bytes: Option<u64>,
+    /// Slot at which the node evaluated the request (`result.context.slot`)
+    pub context_slot: Option<u64>,
+    /// Wall-clock time of the whole request
+    pub total_duration_ms: u64,
+}
+
+impl RequestTelemetry {
+    /// Empty telemetry for a request to `method`
+    #[must_use]
+    pub fn new(method: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Number of HTTP attempts made
+    #[must_use]
+    pub fn attempt_count(&self) -> u32 {
+        u32::try_from(self.attempts.len()).unwrap_or(u32::MAX)
+    }
+
+    /// Attempts beyond the first (including attempts against fallback endpoints)
+    #[must_use]
+    pub fn retries(&self) -> u32 {
+        self.attempt_count().saturating_sub(1)
+    }
+
+    /// Fold in the telemetry of another call made for the same result
+    ///
+    /// Used when one result needs several RPC calls (chunked ranges, consensus).
+    /// Durations are taken as the longest call since the calls run concurrently.
+    pub fn merge(&mut self, other: Self) {
+        self.attempts.extend(other.attempts);
+        self.rate_limit_wait_ms += other.rate_limit_wait_ms;
+        self.rate_limited |= other.rate_limited;
+        self.effective_rate = match (self.effective_rate, other.effective_rate) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        self.http_status = other.http_status.or(self.http_status);
+        self.response_bytes = match (self.response_bytes, other.response_bytes) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+        self.context_slot = self.context_slot.max(other.context_slot);
+        self.total_duration_ms = self.total_duration_ms.max(other.total_duration_ms);
+    }
+}
+
+/// Request parameters for getBlockProduction
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Hash)]
 #[derive(Default)]
 pub struct BlockProductionRequest {
     /// Specific slot range (optional)
     pub range: Option<SlotRange>,
     /// Commitment level (optional) - "processed", "confirmed", or "finalized"
     pub commitment: Option<String>,
+    /// Only return results for this validator identity (optional, base58 encoded)
+    pub identity: Option<String>,
+    /// Resolve exact skipped slots via getLeaderSchedule and getBlocks (extra RPC calls)
+    #[serde(default)]
+    pub include_skipped_slots: bool,
+    /// Join stake data from getVoteAccounts and compute stake-weighted metrics (extra RPC call)
+    #[serde(default)]
+    pub include_stake: bool,
+    /// Attach on-chain validator-info metadata (cached getProgramAccounts call)
+    #[serde(default)]
+    pub include_validator_info: bool,
+    /// Group skip rates by software version from getClusterNodes (extra RPC call)
+    #[serde(default)]
+    pub include_version_breakdown: bool,
 }
 
 
@@ -501,6 +1507,62 @@ pub struct BlockProductionRequest {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_cluster_detection_and_servable_range() {
+        assert_eq!(SolanaCluster::from_genesis_hash(SolanaCluster::MAINNET_BETA_GENESIS_HASH), SolanaCluster::MainnetBeta);
+        assert_eq!(SolanaCluster::from_genesis_hash(SolanaCluster::DEVNET_GENESIS_HASH), SolanaCluster::Devnet);
+        assert_eq!(SolanaCluster::from_genesis_hash("local"), SolanaCluster::Unknown);
+
+        let mut diagnostics = EndpointDiagnostics {
+            rpc_endpoint: "http://localhost:8899".to_string(),
+            healthy: true,
+            version: None,
+            feature_set: None,
+            genesis_hash: None,
+            cluster: SolanaCluster::Unknown,
+            slot: Some(1_000),
+            minimum_ledger_slot: Some(100),
+            first_available_block: Some(150),
+            reference_endpoint: None,
+            reference_slot: None,
+            slot_lag: None,
+            calls: Vec::new(),
+            reference_call: None,
+            checked_at: Utc::now(),
+        };
+
+        let range = diagnostics.servable_range().unwrap();
+        assert_eq!((range.first_slot, range.last_slot), (150, 1_000));
+        assert!(diagnostics.can_serve(&SlotRange { first_slot: 150, last_slot: 900 }));
+        assert!(!diagnostics.can_serve(&SlotRange { first_slot: 120, last_slot: 900 }));
+
+        // A full-history node is still limited to the SlotHistory window
+        diagnostics.slot = Some(300_000_000);
+        let range = diagnostics.servable_range().unwrap();
+        assert_eq!(range.first_slot, 300_000_000 - SLOT_HISTORY_MAX_ENTRIES + 1);
+        assert_eq!(range.last_slot, 300_000_000);
+
+        diagnostics.slot = None;
+        assert!(diagnostics.servable_range().is_none());
+    }
+
+    #[test]
+    fn test_slot_range_chunks() {
+        let range = SlotRange { first_slot: 100, last_slot: 349 };
+        let chunks = range.chunks(100);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!((chunks[0].first_slot, chunks[0].last_slot), (100, 199));
+        assert_eq!((chunks[1].first_slot, chunks[1].last_slot), (200, 299));
+        assert_eq!((chunks[2].first_slot, chunks[2].last_slot), (300, 349));
+
+        let exact = SlotRange { first_slot: 0, last_slot: 199 }.chunks(100);
+        assert_eq!(exact.len(), 2);
+
+        let single = SlotRange { first_slot: 5, last_slot: 10 }.chunks(100);
+        assert_eq!(single.len(), 1);
+        assert_eq!((single[0].first_slot, single[0].last_slot), (5, 10));
+    }
+
     #[test]
     fn test_validator_skip_rate_creation() {
         let validator = ValidatorSkipRate::new(
@@ -605,6 +1667,75 @@ mod tests {
         assert!(weight < 1.0); // Should be reasonable
     }
 
+    #[test]
+    fn test_vote_accounts_stake_by_identity() {
+        let account = |vote: &str, node: &str, stake: u64| RpcVoteAccount {
+            vote_pubkey: vote.to_string(),
+            node_pubkey: node.to_string(),
+            activated_stake: stake,
+            commission: 5,
+            epoch_vote_account: true,
+            last_vote: 100,
+            root_slot: 68,
+        };
+
+        let vote_accounts = VoteAccounts {
+            current: vec![account("vote1", "node1", 1_000), account("vote2", "node2", 500)],
+            delinquent: vec![account("vote3", "node3", 200), account("vote1b", "node1", 10)],
+        };
+
+        let stake = vote_accounts.stake_by_identity();
+        assert_eq!(stake.len(), 3);
+        assert_eq!(stake["node1"].vote_pubkey, "vote1");
+        assert!(!stake["node1"].delinquent);
+        assert!(stake["node3"].delinquent);
+        assert_eq!(stake["node3"].activated_stake, 200);
+    }
+
+    #[test]
+    fn test_validator_info_from_parsed_account() {
+        let account = serde_json::json!({
+            "pubkey": "InfoAccount1111",
+            "account": {
+                "data": {
+                    "parsed": {
+                        "type": "validatorInfo",
+                        "info": {
+                            "configData": {
+                                "name": "Example Validator",
+                                "website": "https://example.com",
+                                "keybaseUsername": "",
+                                "details": "Runs on bare metal"
+                            },
+                            "keys": [
+                                {"pubkey": "Va1idator1nfo111111111111111111111111111111", "signer": false},
+                                {"pubkey": "IdentityPubkey1111", "signer": true}
+                            ]
+                        }
+                    },
+                    "program": "config"
+                }
+            }
+        });
+
+        let (identity, info) = ValidatorInfo::from_parsed_account(&account).unwrap();
+        assert_eq!(identity, "IdentityPubkey1111");
+        assert_eq!(info.name.as_deref(), Some("Example Validator"));
+        assert_eq!(info.website.as_deref(), Some("https://example.com"));
+        assert!(info.keybase_username.is_none()); // empty strings are dropped
+        assert!(info.icon_url.is_none());
+
+        let mut validator = ValidatorSkipRate::new("IdentityPubkey1111".to_string(), 10, 10);
+        assert_eq!(validator.display_name(), "IdentityPubkey1111");
+        validator.validator_info = Some(info);
+        assert_eq!(validator.display_name(), "Example Validator");
+
+        let other = serde_json::json!({
+            "account": {"data": {"parsed": {"type": "stakeConfig", "info": {}}}}
+        });
+        assert!(ValidatorInfo::from_parsed_account(&other).is_none());
+    }
+
     #[test]
     fn test_slot_range() {
         let range = SlotRange {
@@ -621,6 +1752,71 @@ mod tests {
         assert_eq!(same.slot_count(), 0);
     }
 
+    #[test]
+    fn test_epoch_schedule_normal() {
+        let schedule = EpochSchedule {
+            slots_per_epoch: 432_000,
+            leader_schedule_slot_offset: 432_000,
+            warmup: false,
+            first_normal_epoch: 0,
+            first_normal_slot: 0,
+        };
+
+        assert_eq!(schedule.slots_in_epoch(700), 432_000);
+        assert_eq!(schedule.first_slot_in_epoch(700), 302_400_000);
+        assert_eq!(schedule.last_slot_in_epoch(700), 302_831_999);
+        assert_eq!(schedule.epoch_for_slot(302_400_000), 700);
+        assert_eq!(schedule.epoch_for_slot(302_831_999), 700);
+        assert_eq!(schedule.epoch_for_slot(302_832_000), 701);
+    }
+
+    #[test]
+    fn test_epoch_schedule_warmup() {
+        let schedule = EpochSchedule {
+            slots_per_epoch: 8192,
+            leader_schedule_slot_offset: 8192,
+            warmup: true,
+            first_normal_epoch: 8,
+            first_normal_slot: 8160,
+        };
+
+        assert_eq!(schedule.slots_in_epoch(0), 32);
+        assert_eq!(schedule.slots_in_epoch(1), 64);
+        assert_eq!(schedule.first_slot_in_epoch(1), 32);
+        assert_eq!(schedule.last_slot_in_epoch(1), 95);
+        assert_eq!(schedule.first_slot_in_epoch(8), 8160);
+        assert_eq!(schedule.first_slot_in_epoch(9), 8160 + 8192);
+        assert_eq!(schedule.epoch_for_slot(31), 0);
+        assert_eq!(schedule.epoch_for_slot(32), 1);
+        assert_eq!(schedule.epoch_for_slot(8160), 8);
+    }
+
+    #[test]
+    fn test_epoch_context() {
+        let info = EpochInfo {
+            absolute_slot: 1_099,
+            block_height: 1_000,
+            epoch: 5,
+            slot_index: 99,
+            slots_in_epoch: 400,
+            transaction_count: None,
+        };
+        assert_eq!(info.first_slot(), 1_000);
+        assert_eq!(info.last_slot(), 1_399);
+
+        let context = info.epoch_context();
+        assert_eq!(context.epoch, 5);
+        assert_eq!(context.slots_elapsed, 100);
+        assert_eq!(context.slots_remaining, 300);
+        assert_eq!(context.progress_percent, 25.0);
+        assert!(!context.is_complete);
+
+        let finished = EpochContext::new(4, 600, 400, 999);
+        assert_eq!(finished.slots_remaining, 0);
+        assert_eq!(finished.progress_percent, 100.0);
+        assert!(finished.is_complete);
+    }
+
     #[test]
     fn test_validator_performance_category() {
         assert_eq!(
@@ -703,6 +1899,11 @@ mod tests {
         let request = BlockProductionRequest::default();
         assert!(request.range.is_none());
         assert!(request.commitment.is_none());
+        assert!(request.identity.is_none());
+        assert!(!request.include_skipped_slots);
+        assert!(!request.include_stake);
+        assert!(!request.include_validator_info);
+        assert!(!request.include_version_breakdown);
     }
 
     #[test]